@@ -0,0 +1,80 @@
+#[cfg(feature = "day01")]
+use aoc2023::day01::Day01;
+#[cfg(feature = "day02")]
+use aoc2023::day02::Day02;
+#[cfg(feature = "day03")]
+use aoc2023::day03::Day03;
+#[cfg(feature = "day04")]
+use aoc2023::day04::Day04;
+#[cfg(feature = "day05")]
+use aoc2023::day05::Day05;
+#[cfg(feature = "day06")]
+use aoc2023::day06::Day06;
+#[cfg(feature = "day07")]
+use aoc2023::day07::Day07;
+#[cfg(feature = "day08")]
+use aoc2023::day08::Day08;
+#[cfg(feature = "day09")]
+use aoc2023::day09::Day09;
+#[cfg(feature = "day10")]
+use aoc2023::day10::Day10;
+#[cfg(feature = "day11")]
+use aoc2023::day11::Day11;
+#[cfg(feature = "day12")]
+use aoc2023::day12::Day12;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+#[cfg(any_day)]
+mod bench {
+    use aoc2023::examples;
+    use aoc2023::solution::Part;
+    use aoc2023::Solution;
+    use criterion::Criterion;
+    use std::hint::black_box;
+
+    // Some days have a different example for each part
+    pub fn bench_day<S: Solution>(c: &mut Criterion) {
+        let part_one = examples::for_part(S::DAY, Part::One).unwrap().input;
+        let part_two = examples::for_part(S::DAY, Part::Two).map_or(part_one, |e| e.input);
+        let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(part_one))));
+        let input = S::parse(part_one).unwrap();
+        group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&input))));
+        let input = S::parse(part_two).unwrap();
+        group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&input))));
+        group.finish();
+    }
+}
+
+fn days(c: &mut Criterion) {
+    // without any day there is nothing to bench
+    #[cfg(not(any_day))]
+    let _ = c;
+    #[cfg(feature = "day01")]
+    bench::bench_day::<Day01>(c);
+    #[cfg(feature = "day02")]
+    bench::bench_day::<Day02>(c);
+    #[cfg(feature = "day03")]
+    bench::bench_day::<Day03>(c);
+    #[cfg(feature = "day04")]
+    bench::bench_day::<Day04>(c);
+    #[cfg(feature = "day05")]
+    bench::bench_day::<Day05>(c);
+    #[cfg(feature = "day06")]
+    bench::bench_day::<Day06>(c);
+    #[cfg(feature = "day07")]
+    bench::bench_day::<Day07>(c);
+    #[cfg(feature = "day08")]
+    bench::bench_day::<Day08>(c);
+    #[cfg(feature = "day09")]
+    bench::bench_day::<Day09>(c);
+    #[cfg(feature = "day10")]
+    bench::bench_day::<Day10>(c);
+    #[cfg(feature = "day11")]
+    bench::bench_day::<Day11>(c);
+    #[cfg(feature = "day12")]
+    bench::bench_day::<Day12>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
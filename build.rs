@@ -0,0 +1,9 @@
+use std::env;
+
+// `any_day` is set when at least one day is built, for the code that needs a day to do anything
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(any_day)");
+    if env::vars().any(|(key, _)| key.starts_with("CARGO_FEATURE_DAY")) {
+        println!("cargo::rustc-cfg=any_day");
+    }
+}
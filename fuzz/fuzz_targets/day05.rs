@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc2023::days;
+use libfuzzer_sys::fuzz_target;
+
+// Malformed input must be reported as an error, never as a panic
+fuzz_target!(|input: &str| {
+    let _ = days::get(5).unwrap().parse(input);
+});
@@ -0,0 +1,89 @@
+use crate::solution::Part;
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Default location of the known-correct answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-correct answers for the puzzle inputs, keyed by day.
+///
+/// The file has one table per day, with optional `part1` and `part2` entries:
+///
+/// ```toml
+/// [day01]
+/// part1 = 142
+/// part2 = "281"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = read_to_string(path)
+            .with_context(|| format!("could not read file `{}`", path.display()))?;
+        Self::parse(&text).with_context(|| format!("invalid answers file `{}`", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: Table = text.parse()?;
+        let mut answers = BTreeMap::new();
+        for (key, parts) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or(anyhow!("`{}` should be named `dayNN`", key))?;
+            let parts = parts
+                .as_table()
+                .ok_or(anyhow!("`{}` should be a table", key))?;
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(anyhow!("unexpected key `{}.{}`", key, name)),
+                };
+                let answer = match answer {
+                    Value::Integer(n) => n.to_string(),
+                    Value::String(s) => s.clone(),
+                    _ => return Err(anyhow!("`{}.{}` should be a number or a string", key, name)),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_parse() {
+        let answers =
+            Answers::parse("[day01]\npart1 = 142\npart2 = \"281\"\n\n[day12]\npart1 = 21").unwrap();
+        let res = (
+            answers.get(1, Part::One),
+            answers.get(1, Part::Two),
+            answers.get(12, Part::One),
+            answers.get(12, Part::Two),
+        );
+        let exp = (Some("142"), Some("281"), Some("21"), None);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Answers::parse("[day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[first]\npart1 = 1").is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::output::{self, Format, Record};
+use crate::Selection;
+use anyhow::{anyhow, Result};
+use aoc2023::day02::{self, Bag, Game};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Check {
+    game: u8,
+    possible: bool,
+    #[serde(serialize_with = "output::serialize_list")]
+    exceeded: Vec<String>,
+}
+
+impl Check {
+    fn new(game: &Game, bag: &Bag) -> Self {
+        let exceeded = game
+            .draws
+            .keys()
+            .filter(|colour| game.max(colour) > bag.count(colour))
+            .map(|colour| format!("{} {} > {}", game.max(colour), colour, bag.count(colour)))
+            .collect();
+        Self {
+            game: game.id,
+            possible: game.is_possible(bag),
+            exceeded,
+        }
+    }
+}
+
+impl Record for Check {
+    const HEADER: &'static [&'static str] = &["Game", "Possible", "Exceeded"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.game.to_string(),
+            if self.possible { "yes" } else { "no" }.to_string(),
+            self.exceeded.join(", "),
+        ]
+    }
+}
+
+/// Prints whether each game of day 2 is possible with `bag`, and the draws that make it not.
+pub fn run(selection: &Selection, bag: &Bag, format: Format) -> Result<()> {
+    if selection.days()? != [2] {
+        return Err(anyhow!("a bag can only be given for day 2"));
+    }
+    let mut checks = Vec::new();
+    for (input, _) in selection.inputs(2)? {
+        let games = day02::parse(&input)?;
+        checks.extend(games.iter().map(|game| Check::new(game, bag)));
+    }
+    output::print(format, &checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Check;
+    use crate::output;
+    use aoc2023::day02::{self, Bag};
+
+    #[test]
+    fn test_csv() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 20 green\n\
+                     Game 2: 1 blue, 2 green";
+        let games = day02::parse(input).unwrap();
+        let bag = Bag::puzzle();
+        let checks: Vec<Check> = games.iter().map(|game| Check::new(game, &bag)).collect();
+        let mut csv = Vec::new();
+        output::write_csv(&mut csv, &checks).unwrap();
+        let res = String::from_utf8(csv).unwrap();
+        let exp = "game,possible,exceeded\n1,false,20 green > 13\n2,true,\n";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+}
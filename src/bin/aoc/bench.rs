@@ -0,0 +1,141 @@
+use crate::mem;
+use crate::output::{self, Format, Record};
+use crate::Selection;
+use anyhow::{anyhow, Result};
+use aoc2023::days;
+use clap::Args;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of timed runs of every phase
+    #[arg(short = 'n', long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Report the allocations, bytes allocated and peak heap of every phase instead of its
+    /// timings; needs the `mem` feature
+    #[arg(long)]
+    mem: bool,
+}
+
+#[derive(Serialize)]
+pub struct Timing {
+    day: u8,
+    phase: String,
+    iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "output::serialize_nanos")]
+    min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "output::serialize_nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "output::serialize_nanos")]
+    p95: Duration,
+}
+
+impl Timing {
+    pub fn new(day: u8, phase: &str, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        let p95 = (samples.len() * 95).div_ceil(100) - 1;
+        Self {
+            day,
+            phase: phase.to_string(),
+            iterations: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[p95],
+        }
+    }
+}
+
+impl Record for Timing {
+    const HEADER: &'static [&'static str] = &["Day", "Phase", "Min", "Median", "P95"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.day.to_string(),
+            self.phase.clone(),
+            format!("{:.2?}", self.min),
+            format!("{:.2?}", self.median),
+            format!("{:.2?}", self.p95),
+        ]
+    }
+}
+
+pub fn sample<T>(iterations: u32, mut f: impl FnMut() -> Result<T>) -> Result<Vec<Duration>> {
+    let mut samples = Vec::new();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let res = f()?;
+        samples.push(start.elapsed());
+        drop(res);
+    }
+    Ok(samples)
+}
+
+/// Memory used by a single run of every phase, which unlike time does not vary between runs.
+fn memory(args: &BenchArgs, format: Format) -> Result<()> {
+    if !mem::ENABLED {
+        return Err(anyhow!(
+            "allocations are only counted when built with `--features mem`"
+        ));
+    }
+    let mut usages = Vec::new();
+    for day in args.selection.days()? {
+        let puzzle = days::get(day).ok_or(anyhow!("day {} is not solved", day))?;
+        for (input, parts) in args.selection.inputs(day)? {
+            let (parsed, usage) = mem::measure(day, "parse", || puzzle.parse(&input));
+            usages.push(usage);
+            let parsed = parsed?;
+            for part in parts {
+                let phase = format!("part {}", part);
+                let (answer, usage) = mem::measure(day, &phase, || puzzle.solve(&parsed, part));
+                usages.push(usage);
+                answer?;
+            }
+        }
+    }
+    output::print(format, &usages)
+}
+
+pub fn run(args: &BenchArgs, format: Format) -> Result<()> {
+    if args.mem {
+        return memory(args, format);
+    }
+    let mut timings = Vec::new();
+    for day in args.selection.days()? {
+        let puzzle = days::get(day).ok_or(anyhow!("day {} is not solved", day))?;
+        for (input, parts) in args.selection.inputs(day)? {
+            let samples = sample(args.iterations, || puzzle.parse(&input))?;
+            timings.push(Timing::new(day, "parse", samples));
+            let parsed = puzzle.parse(&input)?;
+            for part in parts {
+                let samples = sample(args.iterations, || puzzle.solve(&parsed, part))?;
+                timings.push(Timing::new(day, &format!("part {}", part), samples));
+            }
+        }
+    }
+    output::print(format, &timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timing;
+    use std::time::Duration;
+
+    #[test]
+    fn test_timing() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let res = Timing::new(1, "parse", samples);
+        let res = (res.min, res.median, res.p95);
+        let exp = (
+            Duration::from_millis(1),
+            Duration::from_millis(51),
+            Duration::from_millis(95),
+        );
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::output::Format;
+use crate::Selection;
+use anyhow::{anyhow, Result};
+use aoc2023::day01::{self, Vocabulary};
+use aoc2023::solution::Part;
+
+/// Prints every line of the input of day 1 with its first and last digits underlined, and the
+/// calibration value they make.
+pub fn run(selection: &Selection, format: Format) -> Result<()> {
+    if selection.days()? != [1] {
+        return Err(anyhow!("`--explain` is only supported by day 1"));
+    }
+    if !matches!(format, Format::Text) {
+        return Err(anyhow!("`--explain` only has a text output"));
+    }
+    for (input, parts) in selection.inputs(1)? {
+        for part in parts {
+            let vocabulary = match part {
+                Part::One => Vocabulary::digits(),
+                Part::Two => Vocabulary::english(),
+            };
+            println!("day 1, part {}", part);
+            let calibrations = day01::calibrations(&input, &vocabulary)?;
+            let width = calibrations.len().to_string().len();
+            for (i, calibration) in calibrations.iter().enumerate() {
+                let value = calibration.value();
+                println!("{:>width$}  {} => {}", i + 1, calibration.line, value);
+                println!("{:>width$}  {}", "", calibration.highlight());
+            }
+            println!("sum = {}", day01::calibration_sum(&input, &vocabulary)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anyhow::{anyhow, Result};
+use aoc2023::generate::{generate, Size};
+use clap::Args;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Args)]
+pub struct GenArgs {
+    /// Day to generate an input for
+    day: u8,
+
+    /// Number of lines, at least one: games, cards, hands, histories, records, map nodes or
+    /// ranges per map
+    #[arg(long)]
+    lines: Option<usize>,
+
+    /// Width of the grid, or length of each line
+    #[arg(long)]
+    width: Option<usize>,
+
+    /// Height of the grid
+    #[arg(long)]
+    height: Option<usize>,
+
+    /// Largest number in the input, clamped to what the day can parse
+    #[arg(long)]
+    max: Option<u64>,
+
+    /// Seed of the random generator [default: random, printed on stderr]
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+pub fn run(args: &GenArgs) -> Result<()> {
+    let default = Size::default_for(args.day);
+    let size = Size {
+        lines: args.lines.unwrap_or(default.lines),
+        width: args.width.unwrap_or(default.width),
+        height: args.height.unwrap_or(default.height),
+        max: args.max.unwrap_or(default.max),
+    };
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+            eprintln!("seed: {}", seed);
+            seed
+        }
+    };
+    let input = generate(args.day, &size, seed)
+        .ok_or(anyhow!("day {} has no input generator", args.day))?;
+    print!("{}", input);
+    Ok(())
+}
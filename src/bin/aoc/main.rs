@@ -0,0 +1,170 @@
+#[cfg(feature = "day02")]
+mod bag;
+mod bench;
+#[cfg(feature = "day01")]
+mod explain;
+mod generate;
+mod mem;
+mod new;
+mod output;
+mod readme;
+mod run;
+mod table;
+mod verify;
+mod watch;
+
+use anyhow::{anyhow, Result};
+use aoc2023::days::DAYS;
+use aoc2023::solution::Part;
+use aoc2023::{examples, input};
+use clap::{Args, Parser, Subcommand};
+use std::io::stderr;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t)]
+    output: output::Format,
+
+    /// Log every step of the solvers on stderr, same as `RUST_LOG=aoc2023=trace`
+    #[arg(long, global = true)]
+    trace: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days and print their answers
+    Run(run::RunArgs),
+    /// Time the parsing and both parts of one or more days
+    Bench(bench::BenchArgs),
+    /// Check the answers of one or more days against the known-correct ones
+    Verify(verify::VerifyArgs),
+    /// Print a random, valid input for a day, to stress the solutions
+    Gen(generate::GenArgs),
+    /// Add a new day: its module, example file and fuzz target, registered with the runner
+    New(new::NewArgs),
+    /// Time every day and rewrite the results table of the README
+    Readme(readme::ReadmeArgs),
+    /// Re-run the tests and the solution of a day whenever its source or input changes
+    Watch(watch::WatchArgs),
+}
+
+/// Days and parts to operate on, shared by the subcommands.
+#[derive(Args)]
+struct Selection {
+    /// Days to select: a single day (`7`), an inclusive range (`1..12`) or `all`
+    #[arg(default_value = "all", value_parser = parse_days)]
+    days: Vec<Days>,
+
+    /// Only select one part of each day
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+
+    /// Input file, `-` for stdin; only valid with a single day [default: `dayNN.txt` in the
+    /// input directory, set through `AOC_INPUT_DIR` or `input_dir` in `aoc.toml`]
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Use the examples from the puzzle descriptions instead of the puzzle inputs
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+}
+
+impl Selection {
+    fn days(&self) -> Result<Vec<u8>> {
+        let mut days: Vec<u8> = self.days.iter().flat_map(|d| d.0.iter()).copied().collect();
+        days.sort();
+        days.dedup();
+        if self.input.is_some() && days.len() != 1 {
+            return Err(anyhow!("an input file can only be given for a single day"));
+        }
+        Ok(days)
+    }
+
+    /// Inputs of `day`, each with the selected parts to solve on it.
+    ///
+    /// Examples are picked per part, since some days have a different example for each part.
+    fn inputs(&self, day: u8) -> Result<Vec<(String, Vec<Part>)>> {
+        if !self.example {
+            let input = input::read(day, self.input.as_deref())?;
+            return Ok(vec![(input, self.parts())]);
+        }
+        let mut inputs: Vec<(String, Vec<Part>)> = Vec::new();
+        for part in self.parts() {
+            let example = examples::for_part(day, part)
+                .or_else(|| examples::for_day(day).next())
+                .ok_or(anyhow!("day {} has no example", day))?;
+            match inputs.iter_mut().find(|(input, _)| input == example.input) {
+                Some((_, parts)) => parts.push(part),
+                None => inputs.push((example.input.to_string(), vec![part])),
+            }
+        }
+        Ok(inputs)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Days(Vec<u8>);
+
+fn parse_days(arg: &str) -> Result<Days> {
+    let days = if arg == "all" {
+        DAYS.iter().map(|p| p.day).collect()
+    } else if let Some((first, last)) = arg.split_once("..") {
+        (first.parse::<u8>()?..=last.parse::<u8>()?).collect()
+    } else {
+        vec![arg.parse::<u8>()?]
+    };
+    if let Some(day) = days.iter().find(|&&d| !DAYS.iter().any(|p| p.day == d)) {
+        return Err(anyhow!("day {} is not solved", day));
+    }
+    Ok(Days(days))
+}
+
+fn parse_part(arg: &str) -> Result<Part> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(anyhow!("part should be 1 or 2")),
+    }
+}
+
+/// Logs the spans and events of the solvers on stderr, filtered by `RUST_LOG` or `--trace`.
+///
+/// Without either, no subscriber is installed and the instrumentation is skipped.
+fn init_tracing(trace: bool) {
+    let filter = match EnvFilter::try_from_default_env() {
+        Ok(filter) => filter,
+        Err(_) if trace => EnvFilter::new("aoc2023=trace"),
+        Err(_) => return,
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(stderr)
+        .init();
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    init_tracing(cli.trace);
+    match cli.command {
+        Command::Run(args) => run::run(&args, cli.output),
+        Command::Bench(args) => bench::run(&args, cli.output),
+        Command::Verify(args) => verify::run(&args, cli.output),
+        Command::Gen(args) => generate::run(&args),
+        Command::New(args) => new::run(&args),
+        Command::Readme(args) => readme::run(&args),
+        Command::Watch(args) => watch::run(&args),
+    }
+}
@@ -0,0 +1,159 @@
+use crate::output::Record;
+use serde::Serialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Whether allocations are counted, only when built with the `mem` feature.
+pub const ENABLED: bool = cfg!(feature = "mem");
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "mem")]
+mod counting {
+    use super::{ALLOCATIONS, BYTES, CURRENT, PEAK};
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::Ordering;
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    /// System allocator that counts allocations and tracks the size of the heap.
+    struct Counting;
+
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        grow(size);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+
+        // a reallocation counts as a new allocation of the new size
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, size);
+            if !new.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                BYTES.fetch_add(size, Ordering::Relaxed);
+                match size.checked_sub(layout.size()) {
+                    Some(more) => grow(more),
+                    None => {
+                        CURRENT.fetch_sub(layout.size() - size, Ordering::Relaxed);
+                    }
+                }
+            }
+            new
+        }
+    }
+}
+
+/// Heap usage of a phase of a day.
+#[derive(Serialize)]
+pub struct Usage {
+    day: u8,
+    phase: String,
+    allocations: usize,
+    bytes: usize,
+    /// Largest growth of the heap over its size when the phase started.
+    peak: usize,
+}
+
+/// Runs `f`, counting what it allocates.
+///
+/// Allocations of every thread are counted, so nothing else should run meanwhile.
+pub fn measure<T>(day: u8, phase: &str, f: impl FnOnce() -> T) -> (T, Usage) {
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let res = f();
+    let usage = Usage {
+        day,
+        phase: phase.to_string(),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+    };
+    (res, usage)
+}
+
+/// Size in bytes with a binary unit, e.g. `1.50 KiB`.
+fn human(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", size, UNITS[unit])
+}
+
+impl Record for Usage {
+    const HEADER: &'static [&'static str] = &["Day", "Phase", "Allocations", "Bytes", "Peak"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.day.to_string(),
+            self.phase.clone(),
+            self.allocations.to_string(),
+            human(self.bytes),
+            human(self.peak),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::human;
+
+    #[test]
+    fn test_human() {
+        let res = [0, 1023, 1536, 5 * 1024 * 1024].map(human);
+        let exp = ["0 B", "1023 B", "1.50 KiB", "5.00 MiB"].map(String::from);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[cfg(feature = "mem")]
+    #[test]
+    fn test_measure() {
+        let (v, usage) = super::measure(1, "parse", || vec![0u8; 4096]);
+        assert!(v.len() == 4096);
+        assert!(
+            usage.allocations >= 1,
+            "allocations = {}",
+            usage.allocations
+        );
+        assert!(usage.bytes >= 4096, "bytes = {}", usage.bytes);
+        assert!(usage.peak >= 4096, "peak = {}", usage.peak);
+    }
+}
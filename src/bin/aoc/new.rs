@@ -0,0 +1,258 @@
+use anyhow::{anyhow, Context, Result};
+use clap::Args;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day to add
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Title of the puzzle
+    #[arg(long, default_value = "")]
+    title: String,
+
+    /// Root of the repository
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+const TEMPLATE: &str = r#"use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::{anyhow, Result};
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let loc = Locator::new(DayNN::DAY, input);
+    let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+    if lines.is_empty() {
+        return Err(loc.error(input, "empty input"));
+    }
+    Ok(lines)
+}
+
+pub fn part_one(_lines: &[String]) -> Result<usize> {
+    Err(anyhow!("part one is not solved yet"))
+}
+
+pub fn part_two(_lines: &[String]) -> Result<usize> {
+    Err(anyhow!("part two is not solved yet"))
+}
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = N;
+    const TITLE: &'static str = "TITLE";
+
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Self::Answer> {
+        part_one(lines)
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::Answer> {
+        part_two(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use crate::examples;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("dayNN").unwrap().input;
+        let lines = parse(input).unwrap();
+        let res = part_one(&lines).unwrap();
+        let exp = 0;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("dayNN").unwrap().input;
+        let lines = parse(input).unwrap();
+        let res = part_two(&lines).unwrap();
+        let exp = 0;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+}
+"#;
+
+const FUZZ_TARGET: &str = r#"#![no_main]
+
+use aoc2023::days;
+use libfuzzer_sys::fuzz_target;
+
+// Malformed input must be reported as an error, never as a panic
+fuzz_target!(|input: &str| {
+    let _ = days::get(N).unwrap().parse(input);
+});
+"#;
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "dayNN"
+path = "fuzz_targets/dayNN.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+/// Fills a template in with `day`.
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("NN", &format!("{:02}", day))
+        .replace("= N;", &format!("= {};", day))
+        .replace("(N)", &format!("({})", day))
+}
+
+/// Inserts `line` after the last line of `text` matching `after`.
+fn insert_after_last(text: &str, after: impl Fn(&str) -> bool, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|l| after(l))
+        .ok_or(anyhow!("could not find where to insert `{}`", line))?;
+    lines.insert(last + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn is_day(name: &str) -> bool {
+    name.strip_prefix("day")
+        .is_some_and(|n| n.len() >= 2 && n[..2].chars().all(|c| c.is_ascii_digit()))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("could not read `{}`", path.display()))?;
+    let text = f(&text).with_context(|| format!("could not edit `{}`", path.display()))?;
+    fs::write(path, text).with_context(|| format!("could not write `{}`", path.display()))
+}
+
+fn create(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).with_context(|| format!("could not write `{}`", path.display()))?;
+    println!("created {}", path.display());
+    Ok(())
+}
+
+pub fn run(args: &NewArgs) -> Result<()> {
+    let root = &args.root;
+    let (day, name) = (args.day, format!("day{:02}", args.day));
+    if !root.join("src").join("days.rs").exists() {
+        return Err(anyhow!(
+            "`{}` is not the root of the repository",
+            root.display()
+        ));
+    }
+    let module = root.join("src").join(format!("{}.rs", name));
+    if module.exists() {
+        return Err(anyhow!("day {} already exists", day));
+    }
+
+    let title = format!("{:?}", args.title);
+    create(&module, &fill(TEMPLATE, day).replace("\"TITLE\"", &title))?;
+    create(&root.join("examples").join(format!("{}.txt", name)), "")?;
+    let fuzz = root.join("fuzz");
+    create(
+        &fuzz.join("fuzz_targets").join(format!("{}.rs", name)),
+        &fill(FUZZ_TARGET, day),
+    )?;
+
+    let lib = root.join("src").join("lib.rs");
+    edit(&lib, |text| {
+        let after = |l: &str| l.strip_prefix("pub mod ").is_some_and(is_day);
+        let module = format!("#[cfg(feature = \"{0}\")]\npub mod {0};", name);
+        insert_after_last(text, after, &module)
+    })?;
+    let days = root.join("src").join("days.rs");
+    edit(&days, |text| {
+        let after = |l: &str| {
+            l.trim_start()
+                .strip_prefix("Puzzle::of::<crate::")
+                .is_some_and(is_day)
+        };
+        let puzzle = format!(
+            "    #[cfg(feature = \"{0}\")]\n    Puzzle::of::<crate::{0}::Day{1:02}>(),",
+            name, day
+        );
+        insert_after_last(text, after, &puzzle)
+    })?;
+    // every day is a default feature
+    let manifest = root.join("Cargo.toml");
+    edit(&manifest, |text| {
+        let text = insert_after_last(
+            text,
+            |l| {
+                l.trim()
+                    .strip_prefix('"')
+                    .and_then(|l| l.strip_suffix("\","))
+                    .is_some_and(is_day)
+            },
+            &format!("    \"{}\",", name),
+        )?;
+        let after = |l: &str| l.split_once(" = ").is_some_and(|(f, _)| is_day(f));
+        insert_after_last(&text, after, &format!("{} = []", name))
+    })?;
+    let examples = root.join("src").join("examples.rs");
+    edit(&examples, |text| {
+        let after = |l: &str| l.trim_start().starts_with("example!(");
+        insert_after_last(
+            text,
+            after,
+            &format!("    example!(\"{}\", {}, None, None),", name, day),
+        )
+    })?;
+    edit(&fuzz.join("Cargo.toml"), |text| {
+        Ok(text.to_string() + &fill(FUZZ_BIN, day))
+    })?;
+    for path in [&manifest, &lib, &days, &examples] {
+        println!("updated {}", path.display());
+    }
+
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args([&lib, &days, &examples])
+        .status();
+    if !formatted.is_ok_and(|s| s.success()) {
+        eprintln!("warning: could not run rustfmt, run `cargo fmt` before committing");
+    }
+    println!(
+        "paste the example of day {} into examples/{}.txt and its answers into src/examples.rs",
+        day, name
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fill;
+    use super::insert_after_last;
+    use super::is_day;
+    use super::FUZZ_TARGET;
+
+    #[test]
+    fn test_insert_after_last() {
+        let text = "pub mod day01;\npub mod day02;\npub mod days;\n";
+        let after = |l: &str| l.strip_prefix("pub mod ").is_some_and(is_day);
+        let res = insert_after_last(text, after, "pub mod day03;").unwrap();
+        let exp = "pub mod day01;\npub mod day02;\npub mod day03;\npub mod days;\n";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_fill() {
+        let res = fill(FUZZ_TARGET, 13);
+        assert!(res.contains("days::get(13)"), "result = {}", res);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::table::Table;
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, Default, ValueEnum)]
+pub enum Format {
+    /// Aligned table, for humans
+    #[default]
+    Text,
+    /// Array of records
+    Json,
+    /// Records with a header line
+    Csv,
+}
+
+/// A line of output, serialized as is in the machine-readable formats.
+pub trait Record: Serialize {
+    const HEADER: &'static [&'static str];
+
+    /// Cells of the text table, one per `HEADER` column.
+    fn cells(&self) -> Vec<String>;
+}
+
+pub fn print<R: Record>(format: Format, records: &[R]) -> Result<()> {
+    match format {
+        Format::Text => {
+            let mut table = Table::new(R::HEADER);
+            for record in records {
+                table.push(record.cells());
+            }
+            print!("{}", table);
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        Format::Csv => write_csv(stdout(), records)?,
+    }
+    Ok(())
+}
+
+/// Writes `records` to `writer` as CSV, with a header line.
+pub fn write_csv<R: Record, W: Write>(writer: W, records: &[R]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for record in records {
+        writer.serialize(record)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Lists are joined with `;` by the machine-readable formats, since CSV cells cannot hold one.
+pub fn serialize_list<S: Serializer>(list: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&list.join(";"))
+}
+
+/// Durations are reported in nanoseconds by the machine-readable formats.
+pub fn serialize_nanos<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+pub fn serialize_optional_nanos<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serialize_nanos(duration, serializer),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,121 @@
+use crate::bench::{self, Timing};
+use crate::Selection;
+use anyhow::{anyhow, Context, Result};
+use aoc2023::days;
+use clap::Args;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Markers around the generated section of the README.
+const START: &str = "<!-- results:start -->";
+const END: &str = "<!-- results:end -->";
+
+#[derive(Args)]
+pub struct ReadmeArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of timed runs of every part, the median is reported
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// File to update
+    #[arg(long, default_value = "README.md")]
+    readme: PathBuf,
+}
+
+struct Row {
+    day: u8,
+    title: &'static str,
+    times: [Option<Duration>; 2],
+}
+
+fn table(rows: &[Row]) -> String {
+    let mut table = String::from("| Day | Puzzle | Part 1 | Part 2 | Source |\n");
+    table += "|----:|--------|-------:|-------:|--------|\n";
+    let time = |t: Option<Duration>| t.map(|t| format!("{:.2?}", t)).unwrap_or_default();
+    for row in rows {
+        writeln!(
+            table,
+            "| {} | [{}](https://adventofcode.com/2023/day/{}) | {} | {} | [day{:02}.rs](src/day{:02}.rs) |",
+            row.day,
+            row.title,
+            row.day,
+            time(row.times[0]),
+            time(row.times[1]),
+            row.day,
+            row.day
+        )
+        .unwrap();
+    }
+    table
+}
+
+/// Replaces what is between the markers of `readme` with `section`.
+fn replace_section(readme: &str, section: &str) -> Result<String> {
+    let missing = || anyhow!("expected a section between `{}` and `{}`", START, END);
+    let start = readme.find(START).ok_or_else(missing)? + START.len();
+    let end = start + readme[start..].find(END).ok_or_else(missing)?;
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        section,
+        &readme[end..]
+    ))
+}
+
+pub fn run(args: &ReadmeArgs) -> Result<()> {
+    let mut rows = Vec::new();
+    for day in args.selection.days()? {
+        let puzzle = days::get(day).ok_or(anyhow!("day {} is not solved", day))?;
+        let mut times = [None; 2];
+        for (input, parts) in args.selection.inputs(day)? {
+            let parsed = puzzle.parse(&input)?;
+            for part in parts {
+                let samples = bench::sample(args.iterations, || puzzle.solve(&parsed, part))?;
+                times[part as usize - 1] = Some(Timing::new(day, "", samples).median);
+            }
+        }
+        rows.push(Row {
+            day,
+            title: puzzle.title,
+            times,
+        });
+    }
+    let path = &args.readme;
+    let readme =
+        fs::read_to_string(path).with_context(|| format!("could not read `{}`", path.display()))?;
+    let readme = replace_section(&readme, &table(&rows))
+        .with_context(|| format!("could not update `{}`", path.display()))?;
+    fs::write(path, readme).with_context(|| format!("could not write `{}`", path.display()))?;
+    println!("updated {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::replace_section;
+    use super::table;
+    use super::Row;
+    use std::time::Duration;
+
+    #[test]
+    fn test_replace_section() {
+        let rows = [Row {
+            day: 6,
+            title: "Wait For It",
+            times: [Some(Duration::from_micros(12)), None],
+        }];
+        let readme = "# Title\n<!-- results:start -->\nold\n<!-- results:end -->\nmore\n";
+        let res = replace_section(readme, &table(&rows)).unwrap();
+        let exp = "# Title\n<!-- results:start -->\n\
+                   | Day | Puzzle | Part 1 | Part 2 | Source |\n\
+                   |----:|--------|-------:|-------:|--------|\n\
+                   | 6 | [Wait For It](https://adventofcode.com/2023/day/6) | 12.00µs |  | [day06.rs](src/day06.rs) |\n\
+                   <!-- results:end -->\nmore\n";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        assert!(replace_section("# Title\n", "").is_err());
+    }
+}
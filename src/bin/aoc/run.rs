@@ -0,0 +1,234 @@
+use crate::output::{self, Format, Record};
+use crate::Selection;
+use anyhow::{anyhow, Result};
+use aoc2023::solution::Part;
+use aoc2023::{days, input};
+use clap::Args;
+use serde::Serialize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of days solved concurrently [default: number of CPUs]
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+
+    /// Show the first and last digits found on every line of day 1 instead of the answers
+    #[cfg(feature = "day01")]
+    #[arg(long)]
+    #[cfg_attr(feature = "day02", arg(conflicts_with_all = ["bag", "bag_file"]))]
+    explain: bool,
+
+    /// Check which games of day 2 are possible with a bag of cubes, such as
+    /// `12 red, 13 green, 14 blue`, instead of solving it
+    #[cfg(feature = "day02")]
+    #[arg(long, conflicts_with = "bag_file")]
+    bag: Option<String>,
+
+    /// Same as `--bag`, with the bag read from a file
+    #[cfg(feature = "day02")]
+    #[arg(long)]
+    bag_file: Option<std::path::PathBuf>,
+}
+
+#[derive(Serialize)]
+struct Answer {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    error: Option<String>,
+    #[serde(
+        rename = "parse_ns",
+        serialize_with = "output::serialize_optional_nanos"
+    )]
+    parse: Option<Duration>,
+    #[serde(
+        rename = "duration_ns",
+        serialize_with = "output::serialize_optional_nanos"
+    )]
+    duration: Option<Duration>,
+    checksum: Option<String>,
+}
+
+impl Answer {
+    fn failed(day: u8, part: Part, error: &anyhow::Error) -> Self {
+        Self {
+            day,
+            part: part as u8,
+            answer: None,
+            error: Some(format!("{:#}", error)),
+            parse: None,
+            duration: None,
+            checksum: None,
+        }
+    }
+}
+
+impl Record for Answer {
+    const HEADER: &'static [&'static str] = &["Day", "Part", "Answer", "Parse", "Time", "Checksum"];
+
+    fn cells(&self) -> Vec<String> {
+        let duration = |d: Option<Duration>| d.map(|d| format!("{:.2?}", d)).unwrap_or_default();
+        vec![
+            self.day.to_string(),
+            self.part.to_string(),
+            match (&self.answer, &self.error) {
+                (Some(answer), _) => answer.clone(),
+                (None, error) => format!("error: {}", error.clone().unwrap_or_default()),
+            },
+            duration(self.parse),
+            duration(self.duration),
+            self.checksum
+                .as_ref()
+                .map(|c| c[..12].to_string())
+                .unwrap_or_default(),
+        ]
+    }
+}
+
+/// Runs `f`, turning a panic into an error so that it only fails its own day.
+pub(crate) fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|m| m.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(anyhow!("panicked: {}", message))
+    })
+}
+
+/// Answers of every selected part of `day`, failed parts included.
+fn solve_day(selection: &Selection, day: u8) -> Vec<Answer> {
+    let found = days::get(day)
+        .ok_or(anyhow!("day {} is not solved", day))
+        .and_then(|puzzle| Ok((puzzle, selection.inputs(day)?)));
+    let (puzzle, inputs) = match found {
+        Ok(found) => found,
+        Err(e) => {
+            let parts = selection.parts().into_iter();
+            return parts.map(|part| Answer::failed(day, part, &e)).collect();
+        }
+    };
+    let mut answers = Vec::new();
+    for (input, parts) in inputs {
+        let checksum = input::checksum(&input);
+        let start = Instant::now();
+        let parsed = catch(|| puzzle.parse(&input));
+        let parse = start.elapsed();
+        for part in parts {
+            let parsed = match &parsed {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    answers.push(Answer {
+                        parse: Some(parse),
+                        checksum: Some(checksum.clone()),
+                        ..Answer::failed(day, part, e)
+                    });
+                    continue;
+                }
+            };
+            let start = Instant::now();
+            let answer = catch(|| puzzle.solve(parsed, part));
+            let duration = start.elapsed();
+            let (answer, error) = match answer {
+                Ok(answer) => (Some(answer), None),
+                Err(e) => (None, Some(format!("{:#}", e))),
+            };
+            answers.push(Answer {
+                day,
+                part: part as u8,
+                answer,
+                error,
+                parse: Some(parse),
+                duration: Some(duration),
+                checksum: Some(checksum.clone()),
+            });
+        }
+    }
+    answers
+}
+
+pub fn run(args: &RunArgs, format: Format) -> Result<()> {
+    #[cfg(feature = "day01")]
+    if args.explain {
+        return crate::explain::run(&args.selection, format);
+    }
+    #[cfg(feature = "day02")]
+    {
+        use aoc2023::day02::Bag;
+        let bag = match (&args.bag, &args.bag_file) {
+            (Some(spec), _) => Some(Bag::parse(spec)?),
+            (None, Some(path)) => Some(Bag::load(path)?),
+            (None, None) => None,
+        };
+        if let Some(bag) = bag {
+            return crate::bag::run(&args.selection, &bag, format);
+        }
+    }
+    let days = args.selection.days()?;
+    let jobs = match args.jobs {
+        Some(jobs) => jobs as usize,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    // every worker takes the next day to solve until there are none left
+    let next = AtomicUsize::new(0);
+    let answers = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let solved = solve_day(&args.selection, day);
+                    answers.lock().unwrap().extend(solved);
+                }
+            });
+        }
+    });
+    let mut answers = answers.into_inner().unwrap();
+    answers.sort_by_key(|a| (a.day, a.part));
+    output::print(format, &answers)?;
+    let failures = answers.iter().filter(|a| a.error.is_some()).count();
+    if failures > 0 {
+        return Err(anyhow!("{} parts failed", failures));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn test_catch() {
+        let res = catch(|| -> anyhow::Result<u8> { panic!("no AAA node") })
+            .unwrap_err()
+            .to_string();
+        let exp = "panicked: no AAA node";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = catch(|| Ok(7)).unwrap();
+        let exp = 7;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[cfg(all(feature = "day01", feature = "day02"))]
+    #[test]
+    fn test_explain_conflicts_with_bag() {
+        use crate::Cli;
+        use clap::error::ErrorKind;
+        use clap::Parser;
+
+        for bag in [["--bag", "12 red"], ["--bag-file", "bag.txt"]] {
+            let args = ["aoc", "run", "1", "--explain", bag[0], bag[1]];
+            let res = Cli::try_parse_from(args).err().map(|e| e.kind());
+            let exp = Some(ErrorKind::ArgumentConflict);
+            assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
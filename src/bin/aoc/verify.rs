@@ -0,0 +1,129 @@
+use crate::output::{self, Format, Record};
+use crate::run::catch;
+use crate::Selection;
+use anyhow::{anyhow, Result};
+use aoc2023::answers::{Answers, ANSWERS_FILE};
+use aoc2023::{days, examples};
+use clap::Args;
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// File with the known-correct answers, ignored with `--example`
+    #[arg(long, default_value = ANSWERS_FILE)]
+    answers: PathBuf,
+}
+
+#[derive(Serialize, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Match,
+    Mismatch,
+    Missing,
+    Error,
+}
+
+#[derive(Serialize)]
+struct Check {
+    day: u8,
+    part: u8,
+    expected: Option<String>,
+    answer: Option<String>,
+    status: Status,
+    error: Option<String>,
+}
+
+impl Record for Check {
+    const HEADER: &'static [&'static str] = &["Day", "Part", "Expected", "Answer", "Status"];
+
+    fn cells(&self) -> Vec<String> {
+        let status = match self.status {
+            Status::Match => "match",
+            Status::Mismatch => "mismatch",
+            Status::Missing => "missing",
+            Status::Error => "error",
+        };
+        vec![
+            self.day.to_string(),
+            self.part.to_string(),
+            self.expected.clone().unwrap_or_default(),
+            self.answer
+                .clone()
+                .or(self.error.clone())
+                .unwrap_or_default(),
+            status.to_string(),
+        ]
+    }
+}
+
+pub fn run(args: &VerifyArgs, format: Format) -> Result<()> {
+    let answers = if args.selection.example {
+        Answers::default()
+    } else {
+        Answers::load(&args.answers)?
+    };
+    let mut checks = Vec::new();
+    for day in args.selection.days()? {
+        let puzzle = days::get(day).ok_or(anyhow!("day {} is not solved", day))?;
+        // a day that cannot be solved fails its parts, without stopping the others
+        let inputs = match args.selection.inputs(day) {
+            Ok(inputs) => inputs
+                .into_iter()
+                .map(|(input, parts)| (Ok(input), parts))
+                .collect(),
+            Err(e) => vec![(Err(e), args.selection.parts())],
+        };
+        for (input, parts) in inputs {
+            let parsed = input.and_then(|input| catch(|| puzzle.parse(&input)));
+            for part in parts {
+                let expected = if args.selection.example {
+                    examples::for_part(day, part).and_then(|e| e.answer(part))
+                } else {
+                    answers.get(day, part)
+                };
+                let expected = expected.map(|e| e.to_string());
+                let answer = match &parsed {
+                    Ok(parsed) => catch(|| puzzle.solve(parsed, part)),
+                    Err(e) => Err(anyhow!("{:#}", e)),
+                };
+                let status = match (&answer, &expected) {
+                    (Err(_), _) => Status::Error,
+                    (Ok(_), None) => Status::Missing,
+                    (Ok(answer), Some(expected)) if answer == expected => Status::Match,
+                    (Ok(_), Some(_)) => Status::Mismatch,
+                };
+                let (answer, error) = match answer {
+                    Ok(answer) => (Some(answer), None),
+                    Err(e) => (None, Some(format!("{:#}", e))),
+                };
+                checks.push(Check {
+                    day,
+                    part: part as u8,
+                    expected,
+                    answer,
+                    status,
+                    error,
+                });
+            }
+        }
+    }
+    output::print(format, &checks)?;
+    // an answer missing from the answers file is not verified either, while some
+    // puzzle descriptions give no answer for their example
+    let failures = checks
+        .iter()
+        .filter(|c| match c.status {
+            Status::Match => false,
+            Status::Missing => !args.selection.example,
+            Status::Mismatch | Status::Error => true,
+        })
+        .count();
+    if failures > 0 {
+        return Err(anyhow!("{} answers failed verification", failures));
+    }
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use anyhow::{anyhow, Context, Result};
+use aoc2023::input;
+use clap::Args;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Time to wait for more changes after one, since editors often write a file in several steps.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Input file [default: `dayNN.txt` in the input directory]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Solve the examples from the puzzle description instead of the puzzle input
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+
+    /// Build the solution in release mode; the tests are always built in debug mode
+    #[arg(long)]
+    release: bool,
+}
+
+/// Line of `aoc run --output json`, of which only the answers are compared.
+#[derive(Deserialize)]
+struct Reported {
+    part: u8,
+    answer: Option<String>,
+    error: Option<String>,
+}
+
+/// Answer or error of each part, as last reported.
+type Answers = BTreeMap<u8, String>;
+
+/// Describes how each part changed from `previous` to `current`.
+fn diff(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.map(|p| p.get(part)) {
+            None => format!("part {}: {}", part, answer),
+            Some(None) => format!("part {}: {} (new)", part, answer),
+            Some(Some(old)) if old == answer => format!("part {}: {} (unchanged)", part, answer),
+            Some(Some(old)) => format!("part {}: {} -> {}", part, old, answer),
+        })
+        .collect()
+}
+
+/// Runs the tests of the day, printing their output only if they fail.
+fn test(name: &str) -> Result<bool> {
+    let output = Command::new("cargo")
+        .args(["test", "--quiet", "--lib", "--", &format!("{}::", name)])
+        .output()
+        .context("could not run `cargo test`")?;
+    if !output.status.success() {
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+    Ok(output.status.success())
+}
+
+/// Builds and solves the day in a child process, so that the latest source is used.
+fn solve(args: &WatchArgs) -> Result<Answers> {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--quiet", "--bin", "aoc"]);
+    if args.release {
+        command.arg("--release");
+    }
+    command.args(["--", "--output", "json", "run", &args.day.to_string()]);
+    if let Some(input) = &args.input {
+        command.arg("--input").arg(input);
+    }
+    if args.example {
+        command.arg("--example");
+    }
+    let output = command.output().context("could not run `cargo run`")?;
+    // failed parts are reported along with the others, only a build failure has no output
+    let reported: Vec<Reported> = serde_json::from_slice(&output.stdout).map_err(|_| {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        anyhow!("could not solve day {}", args.day)
+    })?;
+    Ok(reported
+        .into_iter()
+        .map(|r| {
+            let answer = match (r.answer, r.error) {
+                (Some(answer), _) => answer,
+                (None, error) => format!("error: {}", error.unwrap_or_default()),
+            };
+            (r.part, answer)
+        })
+        .collect())
+}
+
+/// Files of the day whose changes trigger a new run, besides its examples.
+fn files(args: &WatchArgs, name: &str) -> Result<Vec<PathBuf>> {
+    let mut files = vec![PathBuf::from("src").join(format!("{}.rs", name))];
+    match &args.input {
+        Some(input) => files.push(input.clone()),
+        None if !args.example => files.push(input::input_dir()?.join(format!("{}.txt", name))),
+        None => {}
+    }
+    Ok(files)
+}
+
+/// Absolute path of `file`, which may not exist yet but whose directory must.
+fn absolute(file: &Path) -> Result<PathBuf> {
+    let dir = match file.parent() {
+        Some(dir) if dir != Path::new("") => dir,
+        _ => Path::new("."),
+    };
+    let dir = dir
+        .canonicalize()
+        .with_context(|| format!("could not watch `{}`", file.display()))?;
+    Ok(dir.join(file.file_name().unwrap_or_default()))
+}
+
+pub fn run(args: &WatchArgs) -> Result<()> {
+    let name = format!("day{:02}", args.day);
+    if !Path::new("src").join(format!("{}.rs", name)).exists() {
+        return Err(anyhow!(
+            "day {} has no source in `src`, run the command from the root of the repository",
+            args.day
+        ));
+    }
+    let files = files(args, &name)?
+        .iter()
+        .map(|f| absolute(f))
+        .collect::<Result<Vec<PathBuf>>>()?;
+    // a day may have several examples, `dayNN.txt` or one per part as `dayNN_a.txt`
+    let examples = absolute(&Path::new("examples").join(&name))?;
+    let watched = |path: &PathBuf| {
+        files.contains(path)
+            || path.parent() == examples.parent()
+                && path
+                    .file_name()
+                    .and_then(|f| f.to_str())
+                    .is_some_and(|f| f.starts_with(&name))
+    };
+
+    // directories are watched rather than files, since editors often replace a file on save
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    let mut dirs: Vec<&Path> = files
+        .iter()
+        .chain([&examples])
+        .filter_map(|f| f.parent())
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+    for file in &files {
+        println!("watching {}", file.display());
+    }
+    println!("watching {}*", examples.display());
+
+    let mut previous: Option<Answers> = None;
+    loop {
+        println!("[day {}] testing", args.day);
+        if test(&name)? {
+            println!("[day {}] solving", args.day);
+            match solve(args) {
+                Ok(answers) => {
+                    for line in diff(previous.as_ref(), &answers) {
+                        println!("{}", line);
+                    }
+                    previous = Some(answers);
+                }
+                Err(e) => eprintln!("error: {:#}", e),
+            }
+        } else {
+            println!("[day {}] tests failed, not solving", args.day);
+        }
+
+        // blocks until one of the files changes, then lets the burst of events settle
+        loop {
+            let event = receiver.recv()??;
+            let modified = !matches!(event.kind, EventKind::Access(_));
+            if modified && event.paths.iter().any(watched) {
+                break;
+            }
+        }
+        while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+    use super::Answers;
+
+    #[test]
+    fn test_diff() {
+        let previous = Answers::from([(1, "142".to_string()), (2, "281".to_string())]);
+        let current = Answers::from([(1, "142".to_string()), (2, "290".to_string())]);
+        let res = diff(Some(&previous), &current);
+        let exp = vec!["part 1: 142 (unchanged)", "part 2: 281 -> 290"];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        let res = diff(None, &current);
+        let exp = vec!["part 1: 142", "part 2: 290"];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+}
@@ -0,0 +1,657 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::read_to_string;
+use std::io::BufRead;
+use std::path::Path;
+
+const DIGIT_MAP: &[(&str, u32)] = &[
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const LITERAL_MAP: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Occurrence of a word of a [`Matcher`] at bytes `start..end` of a line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+#[derive(Default)]
+struct Node {
+    children: Vec<(char, usize)>,
+    // longest proper suffix of this node that is also a node
+    fail: usize,
+    // length and value of every word ending at this node, longest first
+    words: Vec<(usize, u32)>,
+}
+
+impl Node {
+    fn child(&self, chr: char) -> Option<usize> {
+        self.children
+            .iter()
+            .find(|(c, _)| *c == chr)
+            .map(|(_, child)| *child)
+    }
+}
+
+/// Lowercase `chr` when case is ignored, unless that changes its length in bytes, which would
+/// shift the positions of the matches.
+fn fold(chr: char, ignore_case: bool) -> char {
+    if !ignore_case {
+        return chr;
+    }
+    let mut lower = chr.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) if lower.len_utf8() == chr.len_utf8() => lower,
+        _ => chr,
+    }
+}
+
+/// Aho–Corasick automaton finding every occurrence of a set of words in a single pass.
+///
+/// Words are matched character by character, so a match always starts and ends on a character
+/// boundary.
+pub struct Matcher {
+    nodes: Vec<Node>,
+    ignore_case: bool,
+}
+
+impl Matcher {
+    pub fn new(words: &[(&str, u32)]) -> Self {
+        Self::build(words, false)
+    }
+
+    pub fn ignoring_case(words: &[(&str, u32)]) -> Self {
+        Self::build(words, true)
+    }
+
+    fn build(words: &[(&str, u32)], ignore_case: bool) -> Self {
+        let mut nodes = vec![Node::default()];
+        for (word, value) in words.iter().filter(|(w, _)| !w.is_empty()) {
+            let mut node = 0;
+            for chr in word.chars().map(|c| fold(c, ignore_case)) {
+                node = match nodes[node].child(chr) {
+                    Some(child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.push((chr, child));
+                        child
+                    }
+                };
+            }
+            nodes[node].words.push((word.len(), *value));
+        }
+        // failure links, breadth first so that the link of a shorter suffix is always known
+        let mut queue: VecDeque<usize> = nodes[0].children.iter().map(|(_, c)| *c).collect();
+        while let Some(node) = queue.pop_front() {
+            for (chr, child) in nodes[node].children.clone() {
+                let fail = Self::next(&nodes, nodes[node].fail, chr);
+                nodes[child].fail = fail;
+                let suffixes = nodes[fail].words.clone();
+                nodes[child].words.extend(suffixes);
+                queue.push_back(child);
+            }
+        }
+        Self { nodes, ignore_case }
+    }
+
+    fn next(nodes: &[Node], mut node: usize, chr: char) -> usize {
+        loop {
+            if let Some(child) = nodes[node].child(chr) {
+                return child;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = nodes[node].fail;
+        }
+    }
+
+    /// Every occurrence of the words in `text`, overlapping ones included, by end position.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        text.char_indices()
+            .scan(0, |node, (i, chr)| {
+                *node = Self::next(&self.nodes, *node, fold(chr, self.ignore_case));
+                Some((i + chr.len_utf8(), *node))
+            })
+            .flat_map(|(end, node)| {
+                self.nodes[node]
+                    .words
+                    .iter()
+                    .map(move |&(len, value)| Match {
+                        start: end - len,
+                        end,
+                        value,
+                    })
+            })
+    }
+}
+
+/// Words standing for digits, with the value of each.
+///
+/// Besides the builders, a vocabulary can be read from a TOML file with the value of every
+/// word, digits `1` to `9` being included unless `digits = false`:
+///
+/// ```toml
+/// case_insensitive = true
+///
+/// [words]
+/// zero = 0
+/// eins = 1
+/// zwei = 2
+/// zwanzig = 20
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Vocabulary {
+    words: BTreeMap<String, u32>,
+    case_insensitive: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VocabularyFile {
+    #[serde(default = "VocabularyFile::digits")]
+    digits: bool,
+    #[serde(default)]
+    case_insensitive: bool,
+    words: BTreeMap<String, u32>,
+}
+
+impl VocabularyFile {
+    fn digits() -> bool {
+        true
+    }
+}
+
+impl Vocabulary {
+    /// Vocabulary without any word.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Digits `1` to `9`, as in part one.
+    pub fn digits() -> Self {
+        DIGIT_MAP
+            .iter()
+            .fold(Self::new(), |v, (word, value)| v.word(word, *value))
+    }
+
+    /// Digits and their English names, `one` to `nine`, as in part two.
+    pub fn english() -> Self {
+        LITERAL_MAP
+            .iter()
+            .fold(Self::digits(), |v, (word, value)| v.word(word, *value))
+    }
+
+    /// Adds `word`, standing for `value`, in place of any value it already stood for.
+    pub fn word(mut self, word: &str, value: u32) -> Self {
+        self.words.insert(word.to_string(), value);
+        self
+    }
+
+    /// Matches the words whatever their case.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = read_to_string(path)
+            .with_context(|| format!("could not read file `{}`", path.display()))?;
+        Self::parse(&text).with_context(|| format!("invalid vocabulary file `{}`", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: VocabularyFile = toml::from_str(text)?;
+        if let Some(word) = file.words.keys().find(|w| w.is_empty()) {
+            return Err(anyhow!("word `{}` should not be empty", word));
+        }
+        let vocabulary = if file.digits {
+            Self::digits()
+        } else {
+            Self::new()
+        };
+        Ok(file
+            .words
+            .iter()
+            .fold(vocabulary, |v, (word, value)| v.word(word, *value))
+            .case_insensitive(file.case_insensitive))
+    }
+
+    pub fn matcher(&self) -> Matcher {
+        let words: Vec<(&str, u32)> = self.words.iter().map(|(w, v)| (w.as_str(), *v)).collect();
+        Matcher::build(&words, self.case_insensitive)
+    }
+}
+
+/// First and last digits found on a line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Calibration<'a> {
+    pub line: &'a str,
+    pub first: Match,
+    pub last: Match,
+}
+
+impl<'a> Calibration<'a> {
+    /// Text of the line matched by `m`.
+    pub fn token(&self, m: Match) -> &'a str {
+        &self.line[m.start..m.end]
+    }
+
+    /// The first digit times ten plus the last one.
+    pub fn value(&self) -> u64 {
+        self.first.value as u64 * 10 + self.last.value as u64
+    }
+
+    /// Carets under the characters of the first and last digits, to print below the line.
+    pub fn highlight(&self) -> String {
+        let column = |byte: usize| self.line[..byte].chars().count();
+        let mut marks = vec![' '; column(self.line.len())];
+        for m in [self.first, self.last] {
+            marks[column(m.start)..column(m.end)].fill('^');
+        }
+        marks.into_iter().collect::<String>().trim_end().to_string()
+    }
+}
+
+fn calibrate<'a>(matcher: &Matcher, line: &'a str) -> Option<Calibration<'a>> {
+    let mut matches = matcher.find_iter(line);
+    let first = matches.next()?;
+    let last = matches.last().unwrap_or(first);
+    Some(Calibration { line, first, last })
+}
+
+/// Calibration of every line, digits being the words of `vocabulary`.
+pub fn calibrations<'a>(
+    input: &'a str,
+    vocabulary: &Vocabulary,
+) -> Result<Vec<Calibration<'a>>, ParseError> {
+    let loc = Locator::new(Day01::DAY, input);
+    let matcher = vocabulary.matcher();
+    let mut res = Vec::new();
+    for line in input.trim().split('\n') {
+        let calibration = calibrate(&matcher, line);
+        res.push(calibration.ok_or_else(|| loc.error(line, "no digit in line"))?);
+    }
+    Ok(res)
+}
+
+/// Sum of the calibration values of every line, digits being the words of `vocabulary`.
+pub fn calibration_sum(input: &str, vocabulary: &Vocabulary) -> Result<u64, ParseError> {
+    calibration_sum_from(0, input, vocabulary)
+}
+
+/// Same as [`calibration_sum`], adding the calibration values to `total`.
+fn calibration_sum_from(
+    total: u64,
+    input: &str,
+    vocabulary: &Vocabulary,
+) -> Result<u64, ParseError> {
+    let loc = Locator::new(Day01::DAY, input);
+    let mut sum = total;
+    for calibration in calibrations(input, vocabulary)? {
+        sum = sum
+            .checked_add(calibration.value())
+            .ok_or_else(|| loc.error(calibration.line, "calibration sum overflows at"))?;
+    }
+    Ok(sum)
+}
+
+fn line_error(line: usize, text: &str, reason: &str) -> ParseError {
+    ParseError {
+        day: Day01::DAY,
+        line,
+        column: 1,
+        text: text.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Same as [`calibration_sum`], reading one line at a time so that the memory used does not
+/// grow with the size of the input, only with the length of its longest line.
+pub fn calibration_sum_reader<R: BufRead>(reader: R, vocabulary: &Vocabulary) -> Result<u64> {
+    calibration_sum_reader_from(0, reader, vocabulary)
+}
+
+/// Same as [`calibration_sum_reader`], adding the calibration values to `total`.
+fn calibration_sum_reader_from<R: BufRead>(
+    total: u64,
+    mut reader: R,
+    vocabulary: &Vocabulary,
+) -> Result<u64> {
+    let matcher = vocabulary.matcher();
+    let mut line = String::new();
+    let (mut number, mut sum) = (0, total);
+    // blank lines are only an error between lines with digits, as `calibration_sum` trims them
+    let (mut started, mut blank) = (false, None);
+    loop {
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .with_context(|| format!("could not read line {} of the input", number + 1))?;
+        if read == 0 {
+            break;
+        }
+        number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            blank = blank.or(started.then_some(number));
+            continue;
+        }
+        if let Some(blank) = blank {
+            return Err(line_error(blank, "", "no digit in line").into());
+        }
+        started = true;
+        let calibration = calibrate(&matcher, text)
+            .ok_or_else(|| line_error(number, text, "no digit in line"))?;
+        sum = sum
+            .checked_add(calibration.value())
+            .ok_or_else(|| line_error(number, text, "calibration sum overflows at"))?;
+    }
+    Ok(sum)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = String;
+    type Answer = u64;
+
+    // every line needs a digit, spelled out at least, for either part
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        calibrations(input, &Vocabulary::english())?;
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer> {
+        Ok(calibration_sum(input, &Vocabulary::digits())?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer> {
+        Ok(calibration_sum(input, &Vocabulary::english())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::calibration_sum;
+    use super::calibration_sum_from;
+    use super::calibration_sum_reader;
+    use super::calibration_sum_reader_from;
+    use super::calibrations;
+    use super::Day01;
+    use super::Match;
+    use super::Matcher;
+    use super::Vocabulary;
+    use super::DIGIT_MAP;
+    use super::LITERAL_MAP;
+    use crate::examples;
+    use crate::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day01_a").unwrap().input;
+        let res = calibration_sum(input, &Vocabulary::digits()).unwrap();
+        let exp: u64 = 142;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day01_b").unwrap().input;
+        let res = calibration_sum(input, &Vocabulary::english()).unwrap();
+        let exp: u64 = 281;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let matcher = Matcher::new(LITERAL_MAP);
+        let res: Vec<Match> = matcher.find_iter("eightwone").collect();
+        let exp = vec![
+            Match {
+                start: 0,
+                end: 5,
+                value: 8,
+            },
+            Match {
+                start: 4,
+                end: 7,
+                value: 2,
+            },
+            Match {
+                start: 6,
+                end: 9,
+                value: 1,
+            },
+        ];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_suffix_words() {
+        let matcher = Matcher::new(&[("one", 1), ("bone", 2), ("ne", 3)]);
+        let res: Vec<u32> = matcher.find_iter("bonebne").map(|m| m.value).collect();
+        let exp = vec![2, 1, 3, 3];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        let input = "é7sëvenøtwo€";
+        let res = calibration_sum(input, &Vocabulary::english()).unwrap();
+        let exp: u64 = 72;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = Matcher::new(DIGIT_MAP).find_iter("ü9").next();
+        let exp = Some(Match {
+            start: 2,
+            end: 3,
+            value: 9,
+        });
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_vocabulary() {
+        let vocabulary = Vocabulary::parse(
+            "case_insensitive = true\n\
+             [words]\n\
+             zero = 0\n\
+             eins = 1\n\
+             zwei = 2\n\
+             zwanzig = 20",
+        )
+        .unwrap();
+        let input = "ZWEInsxzero\n\
+                     7zwanzigÖ";
+        let res = calibration_sum(input, &vocabulary).unwrap();
+        let exp: u64 = 20 + 90;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = Vocabulary::parse("digits = false\n[words]\nun = 1\ndeux = 2").unwrap();
+        let exp = Vocabulary::new().word("deux", 2).word("un", 1);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        assert!(Vocabulary::parse("[words]\none = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_redefined_word() {
+        let vocabulary = Vocabulary::english().word("one", 7);
+        let res = calibration_sum("one", &vocabulary).unwrap();
+        let exp: u64 = 77;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let file = std::env::temp_dir().join("aoc2023_test_redefined_word.toml");
+        std::fs::write(&file, "[words]\n1 = 5").unwrap();
+        let vocabulary = Vocabulary::load(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        let res = calibration_sum("1", &vocabulary).unwrap();
+        let exp: u64 = 55;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let matcher = Matcher::ignoring_case(&[("drei", 3), ("fünf", 5)]);
+        let res: Vec<Match> = matcher.find_iter("DreiFÜNF").collect();
+        let exp = vec![
+            Match {
+                start: 0,
+                end: 4,
+                value: 3,
+            },
+            Match {
+                start: 4,
+                end: 9,
+                value: 5,
+            },
+        ];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_calibrations() {
+        let input = "xtwone3four\n\
+                     7pqrstsixteen";
+        let res = calibrations(input, &Vocabulary::english())
+            .unwrap()
+            .iter()
+            .map(|c| {
+                (
+                    c.token(c.first),
+                    c.first.start,
+                    c.token(c.last),
+                    c.last.end,
+                    c.value(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let exp = vec![("two", 1, "four", 11, 24), ("7", 0, "six", 9, 76)];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_highlight() {
+        let vocabulary = Vocabulary::english();
+        let res = calibrations("eightwo\nab1c\nzoneight234\nëtwo1", &vocabulary)
+            .unwrap()
+            .iter()
+            .map(|c| c.highlight())
+            .collect::<Vec<_>>();
+        let exp = vec!["^^^^^^^", "  ^", " ^^^      ^", " ^^^^"];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_reader() {
+        let input = examples::get("day01_b").unwrap().input;
+        let res = calibration_sum_reader(input.as_bytes(), &Vocabulary::english()).unwrap();
+        let exp = 281;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let input = "\n\n1abc2\r\n\npqr3stu8vwx\n";
+        let res = calibration_sum_reader(input.as_bytes(), &Vocabulary::digits())
+            .unwrap_err()
+            .to_string();
+        let exp = "day 1, line 4, column 1: no digit in line";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = calibration_sum_reader(&b"1\n\xff2\n"[..], &Vocabulary::digits())
+            .unwrap_err()
+            .to_string();
+        let exp = "could not read line 2 of the input";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_wide_sum() {
+        let vocabulary = Vocabulary::new().word("max", u32::MAX);
+        let input = "max\n".repeat(3).into_bytes();
+        let res = calibration_sum_reader(&input[..], &vocabulary).unwrap();
+        let exp = 3 * 11 * u32::MAX as u64;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        let input = "1abc2\n\
+                     pqr3stu8vwx";
+        let res = calibration_sum_from(u64::MAX - 40, input, &Vocabulary::digits())
+            .unwrap_err()
+            .to_string();
+        let exp = "day 1, line 2, column 1: calibration sum overflows at `pqr3stu8vwx`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let total = u64::MAX - 40;
+        let res = calibration_sum_reader_from(total, input.as_bytes(), &Vocabulary::digits())
+            .unwrap_err()
+            .to_string();
+        let exp = "day 1, line 2, column 1: calibration sum overflows at `pqr3stu8vwx`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let input = "1abc2\n\
+                     pqrstuvwx";
+        let res = calibration_sum(input, &Vocabulary::digits())
+            .unwrap_err()
+            .to_string();
+        let exp = "day 1, line 2, column 1: no digit in line `pqrstuvwx`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = Day01::parse(input).unwrap_err().to_string();
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_calibrations_round_trip(
+            lines in prop::collection::vec(
+                (prop::collection::vec(("[a-z]{0,5}", 1..=9u32), 1..5), "[a-z]{0,5}"),
+                1..10,
+            ),
+        ) {
+            let input = lines
+                .iter()
+                .map(|(digits, rest)| {
+                    let digits = digits.iter().map(|(filler, d)| format!("{}{}", filler, d));
+                    digits.collect::<String>() + rest
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = calibrations(&input, &Vocabulary::digits())
+                .unwrap()
+                .iter()
+                .map(|c| c.value())
+                .collect::<Vec<_>>();
+            let exp = lines
+                .iter()
+                .map(|(digits, _)| (digits[0].1 * 10 + digits[digits.len() - 1].1) as u64)
+                .collect::<Vec<_>>();
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::read_to_string;
+use std::path::Path;
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Game {
+    pub id: u8,
+    /// Cubes of each colour in every draw that showed some.
+    pub draws: BTreeMap<String, Vec<u32>>,
+}
+
+impl Game {
+    pub fn new(id: u8) -> Self {
+        Self {
+            id,
+            draws: BTreeMap::new(),
+        }
+    }
+
+    /// Most cubes of `colour` drawn at once, 0 if it was never drawn.
+    pub fn max(&self, colour: &str) -> u32 {
+        self.draws
+            .get(colour)
+            .and_then(|draws| draws.iter().max())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Whether every draw could have been taken from `bag`.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.draws
+            .keys()
+            .all(|colour| self.max(colour) <= bag.count(colour))
+    }
+}
+
+/// Cubes of each colour in a bag.
+///
+/// A bag is written like a draw, as counts and colours separated by commas or new lines:
+/// `12 red, 13 green, 14 blue`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    /// Bag without any cube.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bag of part one, with 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        Self::new()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14)
+    }
+
+    /// Adds `count` cubes of `colour`.
+    pub fn with(mut self, colour: &str, count: u32) -> Self {
+        self.cubes.insert(colour.to_string(), count);
+        self
+    }
+
+    /// Cubes of `colour` in the bag, 0 if it has none.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(|c| c.as_str())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = read_to_string(path)
+            .with_context(|| format!("could not read file `{}`", path.display()))?;
+        Self::parse(&text).with_context(|| format!("invalid bag file `{}`", path.display()))
+    }
+
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut bag = Self::new();
+        for cubes in spec
+            .split([',', '\n'])
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        {
+            let (count, colour) = cubes
+                .split_once(' ')
+                .ok_or(anyhow!("`{}` should be a count and a colour", cubes))?;
+            let count: u32 = count
+                .parse()
+                .with_context(|| format!("invalid count of cubes `{}`", count))?;
+            let colour = colour.trim();
+            if bag.cubes.contains_key(colour) {
+                return Err(anyhow!("colour `{}` is given twice", colour));
+            }
+            bag = bag.with(colour, count);
+        }
+        Ok(bag)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    let loc = Locator::new(Day02::DAY, input);
+    let mut res: Vec<Game> = Vec::new();
+    for line in input.trim().split('\n') {
+        let (id, sets) = loc.split_once(line.trim_start_matches("Game "), ": ")?;
+        let id: u8 = loc.number(id)?;
+        let mut game = Game::new(id);
+        for set in sets.split("; ") {
+            for color in set.split(", ") {
+                let (n, col) = loc.split_once(color, " ")?;
+                let n: u32 = loc.number(n)?;
+                if col.is_empty() || col.contains(char::is_whitespace) {
+                    return Err(loc.error(color, "invalid color in"));
+                }
+                game.draws.entry(col.to_string()).or_default().push(n);
+            }
+        }
+        res.push(game);
+    }
+    Ok(res)
+}
+
+/// Sum of the ids of the games possible with the cubes of `bag`.
+pub fn part_one(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id as u32)
+        .sum()
+}
+
+/// Sum of the powers of the games, the product of the fewest cubes of each colour each game
+/// is possible with, over the colours of `bag` and those drawn.
+///
+/// A game that never draws a colour of `bag` has a power of 0.
+pub fn part_two(games: &[Game], bag: &Bag) -> Result<u32> {
+    let mut sum: u32 = 0;
+    for game in games {
+        let colours: BTreeSet<&str> = bag
+            .colours()
+            .chain(game.draws.keys().map(String::as_str))
+            .collect();
+        let power = colours
+            .into_iter()
+            .try_fold(1u32, |power, colour| power.checked_mul(game.max(colour)))
+            .and_then(|power| power.checked_add(sum));
+        sum = power.ok_or(anyhow!("sum of powers overflows at game {}", game.id))?;
+    }
+    Ok(sum)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(games: &Self::Input) -> Result<Self::Answer> {
+        Ok(part_one(games, &Bag::puzzle()))
+    }
+
+    fn part_two(games: &Self::Input) -> Result<Self::Answer> {
+        part_two(games, &Bag::puzzle())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use super::Bag;
+    use super::Day02;
+    use super::Game;
+    use crate::examples;
+    use crate::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day02").unwrap().input;
+        let games = parse(input).unwrap();
+        let res = part_one(&games, &Bag::puzzle());
+        let exp: u32 = 8;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day02").unwrap().input;
+        let games = parse(input).unwrap();
+        let res = part_two(&games, &Bag::puzzle()).unwrap();
+        let exp: u32 = 2286;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+                     Game 2: 1 blue, 2 green; 3 purple cube, 4 blue, 1 red";
+        let res = parse(input).unwrap_err().to_string();
+        let exp = "day 2, line 2, column 26: invalid color in `3 purple cube`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_any_bag() {
+        let input = "Game 1: 3 blue, 4 purple; 1 red, 2 green\n\
+                     Game 2: 1 blue, 2 green; 3 purple, 4 blue";
+        let games = parse(input).unwrap();
+        let bag = Bag::parse("4 purple, 4 blue\n2 green").unwrap();
+        let res = part_one(&games, &bag);
+        let exp: u32 = 2;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        // red is drawn by game 1 only, though not in the bag
+        let res = part_two(&games, &bag).unwrap();
+        let exp: u32 = 3 * 4 * 2 + 4 * 2 * 3;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        // a colour of the bag that is never drawn does not make a game impossible, but
+        // leaves it with a power of 0
+        let bag = Bag::parse("4 purple, 4 blue\n2 green\n1 yellow").unwrap();
+        let res = part_one(&games, &bag);
+        let exp: u32 = 2;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = part_two(&games, &bag).unwrap();
+        let exp: u32 = 0;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_missing_colour() {
+        let input = "Game 1: 3 red, 2 green\n\
+                     Game 2: 1 red, 1 green, 1 blue";
+        let games = Day02::parse(input).unwrap();
+        let res = Day02::part_two(&games).unwrap();
+        let exp: u32 = 1;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_bag_parse_error() {
+        let res = Bag::parse("12 red, 13 green, 1 red")
+            .unwrap_err()
+            .to_string();
+        let exp = "colour `red` is given twice";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        assert!(Bag::parse("12 red, green").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            games in prop::collection::vec(
+                (
+                    any::<u8>(),
+                    prop::collection::vec(
+                        prop::collection::vec(
+                            (any::<u32>(), prop::sample::select(vec!["red", "green", "blue"])),
+                            1..4,
+                        ),
+                        1..4,
+                    ),
+                ),
+                1..10,
+            ),
+        ) {
+            let input = games
+                .iter()
+                .map(|(id, sets)| {
+                    let sets = sets
+                        .iter()
+                        .map(|set| {
+                            set.iter()
+                                .map(|(n, color)| format!("{} {}", n, color))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        })
+                        .collect::<Vec<_>>()
+                        .join("; ");
+                    format!("Game {}: {}", id, sets)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = parse(&input).unwrap();
+            let exp = games
+                .iter()
+                .map(|(id, sets)| {
+                    let mut game = Game::new(*id);
+                    for (n, color) in sets.iter().flatten() {
+                        game.draws.entry(color.to_string()).or_default().push(*n);
+                    }
+                    game
+                })
+                .collect::<Vec<_>>();
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
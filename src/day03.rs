@@ -0,0 +1,190 @@
+use crate::grid::Grid;
+use crate::parse::{Locator, ParseError};
+use crate::point::{Direction, Point};
+use crate::Solution;
+use anyhow::Result;
+
+/// Number written left to right from `start` over `len` cells.
+pub struct Number {
+    pub start: Point,
+    pub len: usize,
+    pub value: u64,
+}
+
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<Number>,
+    // index in `numbers` of the number covering each cell
+    ids: Grid<Option<usize>>,
+}
+
+fn is_symbol(chr: char) -> bool {
+    !chr.is_ascii_digit() && chr != '.'
+}
+
+pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let loc = Locator::new(Day03::DAY, input);
+    let grid = Grid::parse(&loc, input.trim(), Some)?;
+    let mut numbers: Vec<Number> = Vec::new();
+    let mut ids = Grid::filled(grid.width(), grid.height(), None);
+    for (pos, chr) in grid.iter() {
+        let Some(digit) = chr.to_digit(10) else {
+            continue;
+        };
+        let left = pos.step(Direction::W);
+        let id = if left.is_some_and(|left| ids[left].is_some()) {
+            numbers.len() - 1
+        } else {
+            numbers.push(Number {
+                start: pos,
+                len: 0,
+                value: 0,
+            });
+            numbers.len() - 1
+        };
+        let number = &mut numbers[id];
+        number.len += 1;
+        number.value = number
+            .value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or_else(|| {
+                let line = input.trim().lines().nth(pos.y).unwrap_or("");
+                loc.error(line, "part number too large in")
+            })?;
+        ids[pos] = Some(id);
+    }
+    Ok(Schematic { grid, numbers, ids })
+}
+
+/// Whether `number` is adjacent to a symbol, diagonally included.
+pub fn is_part_number(schematic: &Schematic, number: &Number) -> bool {
+    let Point { x, y } = number.start;
+    (x..x + number.len).any(|x| {
+        schematic
+            .grid
+            .neighbours8(Point::new(x, y))
+            .any(|p| is_symbol(schematic.grid[p]))
+    })
+}
+
+pub fn part_one(schematic: &Schematic) -> u64 {
+    let mut sum: u64 = 0;
+    for number in &schematic.numbers {
+        if is_part_number(schematic, number) {
+            sum += number.value;
+        }
+    }
+    sum
+}
+
+/// Sum of the gear ratios, the products of the two numbers adjacent to a `*`.
+pub fn part_two(schematic: &Schematic) -> u64 {
+    let mut sum: u64 = 0;
+    for (pos, chr) in schematic.grid.iter() {
+        if *chr != '*' {
+            continue;
+        }
+        let mut adjacent: Vec<usize> = schematic
+            .grid
+            .neighbours8(pos)
+            .filter_map(|p| schematic.ids[p])
+            .collect();
+        adjacent.sort();
+        adjacent.dedup();
+        if adjacent.len() == 2 {
+            sum += schematic.numbers[adjacent[0]].value * schematic.numbers[adjacent[1]].value;
+        }
+    }
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Schematic;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(schematic: &Self::Input) -> Result<Self::Answer> {
+        Ok(part_one(schematic))
+    }
+
+    fn part_two(schematic: &Self::Input) -> Result<Self::Answer> {
+        Ok(part_two(schematic))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use crate::examples;
+    use crate::point::Point;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day03").unwrap().input;
+        let schematic = parse(input).unwrap();
+        let res = part_one(&schematic);
+        let exp: u64 = 4361;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day03").unwrap().input;
+        let schematic = parse(input).unwrap();
+        let res = part_two(&schematic);
+        let exp: u64 = 467835;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            rows in prop::collection::vec(
+                prop::collection::vec(prop::option::of(0..1_000_000u64), 1..8),
+                1..8,
+            ),
+        ) {
+            // a symbol for `None`, and a dot after each number so that they do not merge
+            let mut exp = Vec::new();
+            let mut lines = Vec::new();
+            for (y, row) in rows.iter().enumerate() {
+                let mut line = String::new();
+                for token in row {
+                    match token {
+                        Some(n) => {
+                            exp.push((Point::new(line.len(), y), n.to_string().len(), *n));
+                            line += &format!("{}.", n);
+                        }
+                        None => line.push('*'),
+                    }
+                }
+                lines.push(line);
+            }
+            let width = lines.iter().map(|l| l.len()).max().unwrap();
+            let input = lines
+                .iter()
+                .map(|l| format!("{:.<1$}", l, width))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let schematic = parse(&input).unwrap();
+            let res = schematic
+                .numbers
+                .iter()
+                .map(|n| (n.start, n.len, n.value))
+                .collect::<Vec<_>>();
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::Result;
+use std::collections::HashMap;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Card {
+    pub id: u32,
+    pub win: Vec<u32>,
+    pub have: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    let loc = Locator::new(Day04::DAY, input);
+    let mut cards: Vec<Card> = Vec::new();
+    for line in input.trim().split('\n') {
+        let (card, numbers) = loc.split_once(line, ":")?;
+        let (_, id) = loc.split_once(card, " ")?;
+        let id: u32 = loc.number(id.trim())?;
+        let (before, after) = loc.split_once(numbers, "|")?;
+        let win: Vec<u32> = before
+            .split_whitespace()
+            .map(|n| loc.number(n))
+            .collect::<Result<Vec<u32>, _>>()?;
+        let have: Vec<u32> = after
+            .split_whitespace()
+            .map(|n| loc.number(n))
+            .collect::<Result<Vec<u32>, _>>()?;
+        let card = Card { id, win, have };
+        cards.push(card);
+    }
+    Ok(cards)
+}
+
+pub fn part_one(cards: &[Card]) -> u32 {
+    let mut points = 0;
+    for card in cards {
+        let mut cpoint = 0;
+        for n in &card.win {
+            if card.have.contains(n) {
+                if cpoint == 0 {
+                    cpoint = 1
+                } else {
+                    cpoint *= 2;
+                }
+            }
+        }
+        points += cpoint;
+    }
+    points
+}
+
+/// Number of scratchcards in the end, the won copies included.
+pub fn part_two(cards: &[Card]) -> u32 {
+    let mut n = 0;
+    let mut id_map: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut scards: Vec<u32> = Vec::new();
+    for card in cards {
+        let mut wins: u32 = 0;
+        for w in &card.win {
+            if card.have.contains(w) {
+                wins += 1;
+            }
+        }
+        let mut won: Vec<u32> = Vec::new();
+        for i in card.id + 1..=card.id + wins {
+            won.push(i);
+        }
+        id_map.insert(card.id, won.clone());
+        scards.push(card.id);
+    }
+    while let Some(cn) = scards.pop() {
+        n += 1;
+        let won = id_map.get(&cn).unwrap();
+        for i in won.iter() {
+            scards.push(*i);
+        }
+    }
+    n
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(cards: &Self::Input) -> Result<Self::Answer> {
+        Ok(part_one(cards))
+    }
+
+    fn part_two(cards: &Self::Input) -> Result<Self::Answer> {
+        Ok(part_two(cards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use super::Card;
+    use crate::examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day04").unwrap().input;
+        let cards = parse(input).unwrap();
+        let res = part_one(&cards);
+        let exp = 13;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day04").unwrap().input;
+        let cards = parse(input).unwrap();
+        let res = part_two(&cards);
+        let exp = 30;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\
+                     Card 2: 13 32 20 16 61   61 30 68 82 17 32 24 19";
+        let res = parse(input).unwrap_err().to_string();
+        let exp =
+            "day 4, line 2, column 8: expected `|` in ` 13 32 20 16 61   61 30 68 82 17 32 24 19`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            cards in prop::collection::vec(
+                (
+                    any::<u32>(),
+                    prop::collection::vec(0..100u32, 0..10),
+                    prop::collection::vec(0..100u32, 0..25),
+                ),
+                1..10,
+            ),
+        ) {
+            let join = |numbers: &Vec<u32>| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{:>2}", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let input = cards
+                .iter()
+                .map(|(id, win, have)| format!("Card {:>3}: {} | {}", id, join(win), join(have)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = parse(&input).unwrap();
+            let exp = cards
+                .into_iter()
+                .map(|(id, win, have)| Card { id, win, have })
+                .collect::<Vec<_>>();
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
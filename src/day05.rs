@@ -0,0 +1,259 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::{anyhow, Result};
+use tracing::{trace, trace_span};
+
+pub type Seed = u64;
+/// Inclusive range of numbers, as `(start, end)`.
+pub type SeedRange = (u64, u64);
+/// Inclusive source ranges of a map, as `(start, end, offset to the destination)`.
+pub type Map = Vec<(u64, u64, i64)>;
+
+/// Seeds to plant and the maps from seeds to locations, in order.
+pub fn parse(input: &str) -> Result<(Vec<Seed>, Vec<Map>), ParseError> {
+    let loc = Locator::new(Day05::DAY, input);
+    let sections: Vec<&str> = input.trim().split("\n\n").collect();
+    let seeds_txt = sections[0].trim_start_matches("seeds: ").trim();
+    let seeds = seeds_txt
+        .split_ascii_whitespace()
+        .map(|n| loc.number(n))
+        .collect::<Result<Vec<Seed>, _>>()?;
+    let mut maps = Vec::new();
+    for section in &sections[1..] {
+        let section_txt = loc.split_once(section, ":")?.1;
+        let mut map = Vec::new();
+        for map_txt in section_txt.trim().split('\n') {
+            let numbers = map_txt
+                .split_ascii_whitespace()
+                .map(|n| loc.number::<u64>(n))
+                .collect::<Result<Vec<u64>, _>>()?;
+            let (dst, src, len) = match numbers[..] {
+                [dst, src, len] if len > 0 => (dst, src, len),
+                _ => return Err(loc.error(map_txt, "expected destination, source and length in")),
+            };
+            let end = src.checked_add(len - 1);
+            let offset = i64::try_from(dst).ok().zip(i64::try_from(src).ok());
+            let (end, (dst, src)) = end
+                .zip(offset)
+                .ok_or_else(|| loc.error(map_txt, "range out of bounds in"))?;
+            map.push((src as u64, end, dst - src));
+        }
+        maps.push(map.clone());
+    }
+    Ok((seeds, maps))
+}
+
+/// Number `n` is mapped to by `map`.
+pub fn map_seed(n: Seed, map: &Map) -> Seed {
+    for (start, end, delta) in map {
+        if (*start..=*end).contains(&n) {
+            return (n as i64 + *delta) as u64;
+        }
+    }
+    n
+}
+
+/// Ranges `ranges` are mapped to by `map`, split where they straddle the bounds of its ranges.
+pub fn map_ranges(mut ranges: Vec<SeedRange>, map: &Map) -> Vec<SeedRange> {
+    let mut new_ranges: Vec<SeedRange> = Vec::new();
+    while let Some(range) = ranges.pop() {
+        let mut found = false;
+        for submap in map {
+            // range is fully included in a map
+            if range.0 >= submap.0 && range.1 <= submap.1 {
+                new_ranges.push((
+                    (range.0 as i64 + submap.2) as u64,
+                    (range.1 as i64 + submap.2) as u64,
+                ));
+                found = true;
+            // range is partially included in a map
+            // r (20, 30) m (24, 27)
+            } else if submap.0 > range.0 && submap.1 < range.1 {
+                ranges.push((range.0, submap.0 - 1));
+                new_ranges.push((
+                    (submap.0 as i64 + submap.2) as u64,
+                    (submap.1 as i64 + submap.2) as u64,
+                ));
+                ranges.push((submap.1 + 1, range.1));
+                found = true;
+            // range overlaps with a map on the right
+            // r (20, 30) m (25, 40)
+            } else if range.0 < submap.0 && range.1 >= submap.0 {
+                ranges.push((range.0, (submap.0 as i64 - 1) as u64));
+                new_ranges.push((
+                    (submap.0 as i64 + submap.2) as u64,
+                    (range.1 as i64 + submap.2) as u64,
+                ));
+                found = true;
+            // range overlaps with a map on the left
+            // r (20, 30) m (10, 25)
+            } else if range.1 > submap.1 && range.0 <= submap.1 {
+                ranges.push((submap.1 + 1, range.1));
+                new_ranges.push((
+                    (range.0 as i64 + submap.2) as u64,
+                    (submap.1 as i64 + submap.2) as u64,
+                ));
+                found = true;
+            }
+            // the rest of the range, if any, was pushed back to be mapped on its own
+            if found {
+                break;
+            }
+        }
+        if !found {
+            new_ranges.push((range.0, range.1));
+        }
+    }
+    new_ranges
+}
+
+/// Lowest location of the seeds.
+pub fn part_one(seeds: &[Seed], maps: &[Map]) -> Result<u64> {
+    let mut locations: Vec<u64> = Vec::new();
+    for seed in seeds {
+        let n = maps.iter().fold(*seed, map_seed);
+        locations.push(n);
+    }
+    if let Some(n) = locations.iter().min() {
+        Ok(*n)
+    } else {
+        Err(anyhow!("Should have at least one element"))
+    }
+}
+
+/// Lowest location of the seeds, read as pairs of start and length of ranges of seeds.
+pub fn part_two(seeds: &[Seed], maps: &[Map]) -> Result<u64> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(anyhow!("seeds should come in pairs of start and length"));
+    }
+    let mut ranges: Vec<SeedRange> = Vec::new();
+    for i in (0..seeds.len()).step_by(2) {
+        ranges.push((seeds[i], seeds[i] + seeds[i + 1] - 1));
+    }
+    for (stage, map) in maps.iter().enumerate() {
+        let _span = trace_span!("map", stage).entered();
+        let new_ranges = map_ranges(ranges, map);
+        trace!(ranges = ?new_ranges, "mapped");
+        ranges = new_ranges;
+    }
+    let start_indexes: Vec<u64> = ranges.iter().map(|r| r.0).collect();
+    if let Some(n) = start_indexes.iter().min() {
+        Ok(*n)
+    } else {
+        Err(anyhow!("Should have at least one element"))
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = (Vec<Seed>, Vec<Map>);
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((seeds, maps): &Self::Input) -> Result<Self::Answer> {
+        part_one(seeds, maps)
+    }
+
+    fn part_two((seeds, maps): &Self::Input) -> Result<Self::Answer> {
+        part_two(seeds, maps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::map_ranges;
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use crate::examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day05").unwrap().input;
+        let (seeds, maps) = parse(input).unwrap();
+        let res = part_one(&seeds, &maps).unwrap();
+        let exp = 35;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_map_ranges() {
+        // (90, 99) straddles both ranges of the map and must be mapped once
+        let map = vec![(98, 99, -48), (50, 97, 2)];
+        let mut res = map_ranges(vec![(79, 92), (90, 99)], &map);
+        res.sort();
+        let exp = vec![(50, 51), (81, 94), (92, 99)];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day05").unwrap().input;
+        let (seeds, maps) = parse(input).unwrap();
+        let res = part_two(&seeds, &maps).unwrap();
+        let exp = 46;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 79 14 55 13\n\
+                     \n\
+                     seed-to-soil map:\n\
+                     50 98 2\n\
+                     52 50";
+        let res = parse(input).unwrap_err().to_string();
+        let exp = "day 5, line 5, column 1: expected destination, source and length in `52 50`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            seeds in prop::collection::vec(0..1u64 << 40, 1..10),
+            maps in prop::collection::vec(
+                prop::collection::vec((0..1u64 << 40, 0..1u64 << 40, 1..1u64 << 40), 1..5),
+                0..7,
+            ),
+        ) {
+            let mut input = format!(
+                "seeds: {}",
+                seeds.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(" ")
+            );
+            for (i, map) in maps.iter().enumerate() {
+                input += &format!("\n\nmap-{} map:", i);
+                for (dst, src, len) in map {
+                    input += &format!("\n{} {} {}", dst, src, len);
+                }
+            }
+            let res = parse(&input).unwrap();
+            let exp = (
+                seeds,
+                maps.iter()
+                    .map(|map| {
+                        map.iter()
+                            .map(|(dst, src, len)| (*src, src + len - 1, *dst as i64 - *src as i64))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+
+    #[test]
+    fn test_parse_error_overflow() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n0 18446744073709551615 2";
+        let res = parse(input).unwrap_err().to_string();
+        let exp = "day 5, line 4, column 1: range out of bounds in `0 18446744073709551615 2`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::Result;
+
+pub type Race = (usize, usize);
+
+/// Races as `(time, record distance)`.
+pub fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let loc = Locator::new(Day06::DAY, input);
+    let lines = loc.split_once(input, "\n")?;
+    let times = lines
+        .0
+        .trim_start_matches("Time: ")
+        .split_ascii_whitespace()
+        .map(|n| loc.number::<usize>(n))
+        .collect::<Result<Vec<usize>, _>>()?;
+    let distances = lines
+        .1
+        .trim_start_matches("Distance: ")
+        .split_ascii_whitespace()
+        .map(|n| loc.number::<usize>(n))
+        .collect::<Result<Vec<usize>, _>>()?;
+    if times.len() != distances.len() {
+        return Err(loc.error(lines.1, "expected as many distances as times in"));
+    }
+    let mut races = Vec::new();
+    for i in 0..times.len() {
+        races.push((times[i], distances[i]));
+    }
+    Ok(races)
+}
+
+fn race_options(time: usize) -> Vec<(usize, usize)> {
+    let mut opts = Vec::new();
+    for i in 0..=time {
+        opts.push((i, i * (time - i)));
+    }
+    opts
+}
+
+pub fn part_one(races: &[Race]) -> Result<usize> {
+    let mut n = 1;
+    for race in races {
+        let opts = race_options(race.0);
+        let wins = opts.iter().filter(|&r| r.1 > race.1).collect::<Vec<_>>();
+        if !wins.is_empty() {
+            n *= wins.len();
+        }
+    }
+    Ok(n)
+}
+
+/// Ways to beat the single race whose numbers are those of `races` put end to end.
+pub fn part_two(races: &[Race]) -> Result<usize> {
+    let mut time = 0;
+    let mut distance = 0;
+    for race in races {
+        time = time * 10usize.pow(race.0.to_string().len() as u32) + race.0;
+        distance = distance * 10usize.pow(race.1.to_string().len() as u32) + race.1;
+    }
+    let opts = race_options(time);
+    let wins = opts.iter().filter(|&r| r.1 > distance).collect::<Vec<_>>();
+    Ok(wins.len())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = Vec<Race>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(races: &Self::Input) -> Result<Self::Answer> {
+        part_one(races)
+    }
+
+    fn part_two(races: &Self::Input) -> Result<Self::Answer> {
+        part_two(races)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use super::race_options;
+    use crate::examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_race_options() {
+        let res = race_options(7);
+        let exp = vec![
+            (0, 0),
+            (1, 6),
+            (2, 10),
+            (3, 12),
+            (4, 12),
+            (5, 10),
+            (6, 6),
+            (7, 0),
+        ];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day06").unwrap().input;
+        let races = parse(input).unwrap();
+        let res = part_one(&races).unwrap();
+        let exp = 288;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day06").unwrap().input;
+        let races = parse(input).unwrap();
+        let res = part_two(&races).unwrap();
+        let exp = 71503;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(races in prop::collection::vec(any::<(usize, usize)>(), 1..5)) {
+            let times = races.iter().map(|r| format!(" {:>4}", r.0)).collect::<String>();
+            let distances = races.iter().map(|r| format!(" {:>4}", r.1)).collect::<String>();
+            let input = format!("Time: {}\nDistance: {}\n", times, distances);
+            let res = parse(&input).unwrap();
+            let exp = races;
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
@@ -1,52 +1,60 @@
-use anyhow::{anyhow, Context, Result};
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::Result;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs::read_to_string;
 
-type Hand = Vec<usize>;
+pub type Hand = Vec<usize>;
 
-fn card_value(label: char, jolly: bool) -> Result<usize> {
+fn card_value(label: char, jolly: bool) -> Option<usize> {
     match label {
-        '2' => Ok(2),
-        '3' => Ok(3),
-        '4' => Ok(4),
-        '5' => Ok(5),
-        '6' => Ok(6),
-        '7' => Ok(7),
-        '8' => Ok(8),
-        '9' => Ok(9),
-        'T' => Ok(10),
+        '2' => Some(2),
+        '3' => Some(3),
+        '4' => Some(4),
+        '5' => Some(5),
+        '6' => Some(6),
+        '7' => Some(7),
+        '8' => Some(8),
+        '9' => Some(9),
+        'T' => Some(10),
         'J' => {
             if jolly {
-                Ok(1)
+                Some(1)
             } else {
-                Ok(11)
+                Some(11)
             }
         }
-        'Q' => Ok(12),
-        'K' => Ok(13),
-        'A' => Ok(14),
-        _ => Err(anyhow!("Invalid label {}", label)),
+        'Q' => Some(12),
+        'K' => Some(13),
+        'A' => Some(14),
+        _ => None,
     }
 }
 
-fn parse(input: &str, jolly: bool) -> Result<Vec<(Hand, usize)>> {
+/// Hands with their bids; with `jolly`, `J` is a joker valued below `2`.
+pub fn parse(input: &str, jolly: bool) -> Result<Vec<(Hand, usize)>, ParseError> {
+    let loc = Locator::new(Day07::DAY, input);
     let lines = input.trim().split('\n').collect::<Vec<&str>>();
     let mut res = Vec::new();
     for line in lines {
-        let (cards, bid) = line
-            .split_once(' ')
-            .ok_or(anyhow!("line should have two elements"))?;
+        let (cards, bid) = loc.split_once(line, " ")?;
         let values = cards
-            .chars()
-            .map(|l| card_value(l, jolly))
+            .char_indices()
+            .map(|(i, l)| {
+                card_value(l, jolly)
+                    .ok_or_else(|| loc.error(&cards[i..i + l.len_utf8()], "invalid label"))
+            })
             .collect::<Result<Hand, _>>()?;
-        res.push((values, bid.parse::<usize>()?));
+        if values.len() != 5 {
+            return Err(loc.error(cards, "hand should have five cards"));
+        }
+        res.push((values, loc.number(bid)?));
     }
     Ok(res)
 }
 
-fn hand_type(hand: &Hand) -> usize {
+/// Strength of the type of `hand`, from 0 for a high card to 6 for five of a kind.
+pub fn hand_type(hand: &Hand) -> usize {
     assert!(hand.len() == 5);
     let mut grouped: HashMap<usize, usize> = HashMap::new();
     for value in hand {
@@ -80,7 +88,8 @@ fn hand_type(hand: &Hand) -> usize {
     }
 }
 
-fn best_hand_type(hand: &Hand) -> usize {
+/// Strongest type `hand` can have, its jokers standing for any card.
+pub fn best_hand_type(hand: &Hand) -> usize {
     if !hand.contains(&1) {
         return hand_type(hand);
     }
@@ -144,7 +153,7 @@ fn custom_cmp(left: &(Hand, usize), right: &(Hand, usize), jolly: bool) -> Order
     }
 }
 
-fn part_one(hands: &[(Hand, usize)]) -> Result<usize> {
+pub fn part_one(hands: &[(Hand, usize)]) -> Result<usize> {
     let mut hands = hands.to_owned();
     hands.sort_by(|a, b| custom_cmp(a, b, false));
     let mut total = 0;
@@ -154,7 +163,8 @@ fn part_one(hands: &[(Hand, usize)]) -> Result<usize> {
     Ok(total)
 }
 
-fn part_two(hands: &[(Hand, usize)]) -> Result<usize> {
+/// Total winnings, for hands parsed with jokers.
+pub fn part_two(hands: &[(Hand, usize)]) -> Result<usize> {
     let mut hands = hands.to_owned();
     hands.sort_by(|a, b| custom_cmp(a, b, true));
     let mut total = 0;
@@ -164,24 +174,40 @@ fn part_two(hands: &[(Hand, usize)]) -> Result<usize> {
     Ok(total)
 }
 
-fn main() -> Result<()> {
-    let input = read_to_string("./input/day07.txt").with_context(|| "could not read input file")?;
-    let hands = parse(&input, false)?;
-    println!("Part one: {}", part_one(&hands)?);
-    let hands = parse(&input, true)?;
-    println!("Part two: {}", part_two(&hands)?);
-    Ok(())
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input = (Vec<(Hand, usize)>, Vec<(Hand, usize)>);
+    type Answer = usize;
+
+    // Jokers are valued differently in the two parts, so the hands are parsed once per part
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse(input, false)?, parse(input, true)?))
+    }
+
+    fn part_one((hands, _): &Self::Input) -> Result<Self::Answer> {
+        part_one(hands)
+    }
+
+    fn part_two((_, hands): &Self::Input) -> Result<Self::Answer> {
+        part_two(hands)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::best_hand_type;
-    use crate::compare_hands;
-    use crate::hand_type;
-    use crate::parse;
-    use crate::part_one;
-    use crate::part_two;
-    use std::fs::read_to_string;
+    use super::best_hand_type;
+    use super::card_value;
+    use super::compare_hands;
+    use super::hand_type;
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use crate::examples;
+    use proptest::prelude::*;
 
     #[test]
     fn test_hand_type_five_of_a_kind() {
@@ -271,8 +297,8 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = read_to_string("./input/day07_test.txt").unwrap();
-        let hands = parse(&input, false).unwrap();
+        let input = examples::get("day07").unwrap().input;
+        let hands = parse(input, false).unwrap();
         let res = part_one(&hands).unwrap();
         let exp = 6440;
         assert!(res == exp, "result = {}, expected = {}", res, exp);
@@ -280,10 +306,42 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = read_to_string("./input/day07_test.txt").unwrap();
-        let hands = parse(&input, true).unwrap();
+        let input = examples::get("day07").unwrap().input;
+        let hands = parse(input, true).unwrap();
         let res = part_two(&hands).unwrap();
         let exp = 5905;
         assert!(res == exp, "result = {}, expected = {}", res, exp);
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "32T3K 765\n\
+                     T55X5 684";
+        let res = parse(input, false).unwrap_err().to_string();
+        let exp = "day 7, line 2, column 4: invalid label `X`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            hands in prop::collection::vec(("[2-9TJQKA]{5}", any::<usize>()), 1..10),
+            jolly in any::<bool>(),
+        ) {
+            let input = hands
+                .iter()
+                .map(|(cards, bid)| format!("{} {}", cards, bid))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = parse(&input, jolly).unwrap();
+            let exp = hands
+                .iter()
+                .map(|(cards, bid)| {
+                    let hand = cards.chars().map(|c| card_value(c, jolly).unwrap()).collect();
+                    (hand, *bid)
+                })
+                .collect::<Vec<_>>();
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
 }
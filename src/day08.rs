@@ -0,0 +1,192 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::Result;
+use gcd::Gcd;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+pub type NodeMap = HashMap<String, (String, String)>;
+
+static NODE: OnceLock<Regex> = OnceLock::new();
+
+/// Left and right instructions, and the node each node leads to for either.
+pub fn parse(input: &str) -> Result<(Vec<char>, NodeMap), ParseError> {
+    let loc = Locator::new(Day08::DAY, input);
+    let (instr, nodes) = loc.split_once(input, "\n\n")?;
+    loc.expect_chars(instr, "LR")?;
+    if instr.is_empty() {
+        return Err(loc.error(instr, "no instructions"));
+    }
+    let instr = instr.chars().collect::<Vec<char>>();
+    let re = NODE.get_or_init(|| {
+        Regex::new(r"^(?<src>\w\w\w) = \((?<left>\w\w\w), (?<right>\w\w\w)\)$").unwrap()
+    });
+    let mut nmap: NodeMap = HashMap::new();
+    let mut targets = Vec::new();
+    for line in nodes.trim_end().lines() {
+        let (_, [src, left, right]) = re
+            .captures(line)
+            .ok_or_else(|| loc.error(line, "invalid node"))?
+            .extract();
+        nmap.insert(src.to_string(), (left.to_string(), right.to_string()));
+        targets.extend([left, right]);
+    }
+    // the walks follow every node they reach
+    if let Some(target) = targets.into_iter().find(|t| !nmap.contains_key(*t)) {
+        return Err(loc.error(target, "undefined node"));
+    }
+    Ok((instr, nmap))
+}
+
+pub fn part_one(instr: &[char], nodes: &NodeMap) -> usize {
+    let mut i = 0;
+    let mut n = 0;
+    let chr_len = instr.len();
+    let mut cur_node = "AAA";
+    loop {
+        if cur_node == "ZZZ" {
+            break;
+        }
+        cur_node = if instr[i] == 'L' {
+            &nodes.get(cur_node).unwrap().0
+        } else {
+            &nodes.get(cur_node).unwrap().1
+        };
+        n += 1;
+        i += 1;
+        i %= chr_len;
+    }
+    n
+}
+
+/// Steps until every node ending with `A` simultaneously reaches a node ending with `Z`.
+pub fn part_two(instr: &[char], nodes: &NodeMap) -> usize {
+    let mut i = 0;
+    let mut n = 0;
+    let chr_len = instr.len();
+    let mut cur_nodes: Vec<&str> = nodes
+        .keys()
+        .filter(|n| n.ends_with('A'))
+        .map(|n| n.as_str())
+        .collect();
+    let mut node_period: HashMap<usize, usize> = HashMap::new();
+    loop {
+        for (idx, node) in cur_nodes.to_owned().iter().enumerate() {
+            if node.ends_with('Z') && !node_period.contains_key(&idx) {
+                node_period.insert(idx, n);
+            }
+            if instr[i] == 'L' {
+                cur_nodes[idx] = &nodes.get(*node).unwrap().0;
+            } else {
+                cur_nodes[idx] = &nodes.get(*node).unwrap().1;
+            };
+        }
+        if node_period.len() == cur_nodes.len() {
+            break;
+        }
+        n += 1;
+        i += 1;
+        i %= chr_len;
+    }
+    let mut res = 1;
+    for i in 0..node_period.len() {
+        res = res * node_period[&i] / res.gcd(node_period[&i])
+    }
+    res
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = (Vec<char>, NodeMap);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((instr, nodes): &Self::Input) -> Result<Self::Answer> {
+        Ok(part_one(instr, nodes))
+    }
+
+    fn part_two((instr, nodes): &Self::Input) -> Result<Self::Answer> {
+        Ok(part_two(instr, nodes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use super::NodeMap;
+    use crate::examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one_a() {
+        let input = examples::get("day08_a").unwrap().input;
+        let (instr, nodes) = parse(input).unwrap();
+        let res = part_one(&instr, &nodes);
+        let exp = 2;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_one_b() {
+        let input = examples::get("day08_b").unwrap().input;
+        let (instr, nodes) = parse(input).unwrap();
+        let res = part_one(&instr, &nodes);
+        let exp = 6;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day08_c").unwrap().input;
+        let (instr, nodes) = parse(input).unwrap();
+        let res = part_two(&instr, &nodes);
+        let exp = 6;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let res = parse(input).unwrap_err().to_string();
+        let exp = "day 8, line 1, column 1: no instructions";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, CCC)\n";
+        let res = parse(input).unwrap_err().to_string();
+        let exp = "day 8, line 3, column 8: undefined node `BBB`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            instr in "[LR]{1,20}",
+            nodes in prop::collection::hash_set("[A-Z0-9]{3}", 1..10).prop_flat_map(|names| {
+                // every node leads to defined nodes
+                let names: Vec<String> = names.into_iter().collect();
+                let target = prop::sample::select(names.clone());
+                let targets = prop::collection::vec((target.clone(), target), names.len());
+                (Just(names), targets).prop_map(|(names, targets)| {
+                    names.into_iter().zip(targets).collect::<NodeMap>()
+                })
+            }),
+        ) {
+            let mut input = format!("{}\n", instr);
+            for (src, (left, right)) in &nodes {
+                input += &format!("\n{} = ({}, {})", src, left, right);
+            }
+            let res = parse(&input).unwrap();
+            let exp: (Vec<char>, NodeMap) = (instr.chars().collect(), nodes);
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
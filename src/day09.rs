@@ -0,0 +1,110 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::Result;
+
+pub type Hist = Vec<isize>;
+
+pub fn parse(input: &str) -> Result<Vec<Hist>, ParseError> {
+    let loc = Locator::new(Day09::DAY, input);
+    let mut res: Vec<Hist> = Vec::new();
+    for line in input.lines() {
+        let hist: Hist = line
+            .split_ascii_whitespace()
+            .map(|n| loc.number::<isize>(n))
+            .collect::<Result<Vec<isize>, _>>()?;
+        res.push(hist);
+    }
+    Ok(res)
+}
+
+/// Sum of the values extrapolated after each history, or before it for part two.
+pub fn solve(histories: &[Hist], part_one: bool) -> isize {
+    let mut n = 0;
+    for hist in histories.iter() {
+        let mut seqs: Vec<Box<Hist>> = Vec::new();
+        seqs.push(Box::new(hist.to_owned()));
+        while !seqs.last().unwrap().iter().all(|n| *n == 0) {
+            let mut nseq: Hist = Vec::new();
+            for i in 0..seqs.last().unwrap().len() - 1 {
+                nseq.push(seqs.last().unwrap()[i + 1] - seqs.last().unwrap()[i]);
+            }
+            seqs.push(Box::new(nseq));
+        }
+        let mut lastval: Vec<isize> = Vec::new();
+        for (i, seq) in seqs.iter().rev().enumerate() {
+            if i == 0 {
+                lastval.push(0);
+            } else if part_one {
+                lastval.push(seq.last().unwrap() + lastval.last().unwrap());
+            } else {
+                lastval.push(seq.first().unwrap() - lastval.last().unwrap());
+            }
+        }
+        n += lastval.last().unwrap();
+    }
+    n
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Vec<Hist>;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(histories: &Self::Input) -> Result<Self::Answer> {
+        Ok(solve(histories, true))
+    }
+
+    fn part_two(histories: &Self::Input) -> Result<Self::Answer> {
+        Ok(solve(histories, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::solve;
+    use crate::examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day09").unwrap().input;
+        let histories = parse(input).unwrap();
+        let res = solve(&histories, true);
+        let exp = 114;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day09").unwrap().input;
+        let histories = parse(input).unwrap();
+        let res = solve(&histories, false);
+        let exp = 2;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            histories in prop::collection::vec(prop::collection::vec(any::<isize>(), 1..20), 1..10),
+        ) {
+            let input = histories
+                .iter()
+                .map(|h| h.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = parse(&input).unwrap();
+            let exp = histories;
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
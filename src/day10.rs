@@ -0,0 +1,189 @@
+use crate::grid::Grid;
+use crate::parse::{Locator, ParseError};
+use crate::point::{Direction, Point};
+use crate::Solution;
+use anyhow::{anyhow, Result};
+use geo::algorithm::Contains;
+use geo::geometry::LineString;
+use geo::geometry::Polygon;
+use geo::point;
+use tracing::{trace, trace_span};
+
+pub type Diagram = Grid<char>;
+
+/// Directions a pipe connects, `None` for ground and the start tile.
+pub fn connections(pipe: char) -> Option<[Direction; 2]> {
+    match pipe {
+        '|' => Some([Direction::N, Direction::S]),
+        '-' => Some([Direction::E, Direction::W]),
+        'L' => Some([Direction::N, Direction::E]),
+        'J' => Some([Direction::N, Direction::W]),
+        '7' => Some([Direction::S, Direction::W]),
+        'F' => Some([Direction::E, Direction::S]),
+        _ => None,
+    }
+}
+
+/// Diagram and the position of its start tile.
+pub fn parse(input: &str) -> Result<(Diagram, Point), ParseError> {
+    let loc = Locator::new(Day10::DAY, input);
+    let diagram = Grid::parse(&loc, input, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+    let start = diagram
+        .iter()
+        .find(|(_, chr)| **chr == 'S')
+        .map(|(pos, _)| pos)
+        .ok_or_else(|| loc.error(input, "missing start tile `S`"))?;
+    Ok((diagram, start))
+}
+
+fn start_shape(diagram: &Diagram, start: Point) -> Result<char> {
+    let connected: Vec<Direction> = Direction::CARDINAL
+        .into_iter()
+        .filter(|d| {
+            start
+                .step(*d)
+                .and_then(|p| diagram.get(p))
+                .and_then(|c| connections(*c))
+                .is_some_and(|c| c.contains(&d.opposite()))
+        })
+        .collect();
+    "|-LJ7F"
+        .chars()
+        .find(|pipe| connections(*pipe).is_some_and(|c| c[..] == connected[..]))
+        .ok_or(anyhow!("any pipe should connect exactly two directions"))
+}
+
+/// Tiles of the loop going through `start`, in order.
+pub fn path(diagram: &Diagram, start: Point) -> Result<Vec<Point>> {
+    let mut heading = connections(start_shape(diagram, start)?).unwrap()[0];
+    let mut pos = start;
+    let mut path = vec![start];
+    let _span = trace_span!("path", %start).entered();
+    loop {
+        pos = pos
+            .step(heading)
+            .filter(|p| diagram.get(*p).is_some())
+            .ok_or(anyhow!("the loop leaves the diagram at {}", pos))?;
+        let pipe = diagram[pos];
+        if pipe == 'S' {
+            break;
+        }
+        let [a, b] = connections(pipe)
+            .filter(|c| c.contains(&heading.opposite()))
+            .ok_or(anyhow!("the loop is broken at {}", pos))?;
+        heading = if a == heading.opposite() { b } else { a };
+        trace!(%pos, %pipe, ?heading, "step");
+        path.push(pos);
+    }
+    Ok(path)
+}
+
+pub fn part_one(diagram: &Diagram, start: Point) -> Result<usize> {
+    let path = path(diagram, start)?;
+    Ok(path.len() / 2)
+}
+
+fn is_tile_enclosed(tile: Point, path: &[Point]) -> bool {
+    let p = point!(x: tile.x as f32, y: tile.y as f32);
+    let poly: Polygon<_> = Polygon::new(
+        LineString::from(
+            path.iter()
+                .map(|p| (p.x as f32, p.y as f32))
+                .collect::<Vec<(_, _)>>(),
+        ),
+        vec![],
+    );
+    poly.contains(&p)
+}
+
+/// Number of tiles enclosed by the loop.
+pub fn part_two(diagram: &Diagram, start: Point) -> Result<usize> {
+    let path = path(diagram, start)?;
+    let mut count = 0;
+    for tile in diagram.positions() {
+        if is_tile_enclosed(tile, &path) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input = (Diagram, Point);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((diagram, start): &Self::Input) -> Result<Self::Answer> {
+        part_one(diagram, *start)
+    }
+
+    fn part_two((diagram, start): &Self::Input) -> Result<Self::Answer> {
+        part_two(diagram, *start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use crate::examples;
+    use crate::grid::Grid;
+    use crate::point::Point;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one_a() {
+        let input = examples::get("day10_a").unwrap().input;
+        let (diagram, start) = parse(input).unwrap();
+        let res = part_one(&diagram, start).unwrap();
+        let exp = 4;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_one_b() {
+        let input = examples::get("day10_b").unwrap().input;
+        let (diagram, start) = parse(input).unwrap();
+        let res = part_one(&diagram, start).unwrap();
+        let exp = 8;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day10_c").unwrap().input;
+        let (diagram, start) = parse(input).unwrap();
+        let res = part_two(&diagram, start).unwrap();
+        let exp = 4;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            (width, height, tiles, start) in (1..10usize, 1..10usize).prop_flat_map(|(w, h)| {
+                (
+                    Just(w),
+                    Just(h),
+                    prop::collection::vec(prop::sample::select(b"|-LJ7F.".to_vec()), w * h),
+                    (0..w, 0..h),
+                )
+            }),
+        ) {
+            let mut diagram = Grid::new(width, height, tiles.iter().map(|t| *t as char).collect());
+            diagram[start] = 'S';
+            let res = parse(&diagram.to_string()).unwrap();
+            let exp = (diagram, Point::new(start.0, start.1));
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
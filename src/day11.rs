@@ -0,0 +1,142 @@
+use crate::grid::Grid;
+use crate::parse::{Locator, ParseError};
+use crate::point::Point;
+use crate::Solution;
+use anyhow::Result;
+use std::collections::HashMap;
+
+pub type Galaxy = Vec<Point>;
+type Distances = HashMap<(Point, Point), usize>;
+
+/// Galaxies of the image with its empty rows and columns.
+pub fn parse(input: &str) -> Result<(Galaxy, Vec<usize>, Vec<usize>), ParseError> {
+    let loc = Locator::new(Day11::DAY, input);
+    let image = Grid::parse(&loc, input, |c| "#.".contains(c).then_some(c))?;
+    let galaxies = image
+        .iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|(pos, _)| pos)
+        .collect();
+    let rows = (0..image.height())
+        .filter(|y| image.row(*y).iter().all(|c| *c == '.'))
+        .collect();
+    let cols = (0..image.width())
+        .filter(|x| image.column(*x).all(|c| *c == '.'))
+        .collect();
+    Ok((galaxies, rows, cols))
+}
+
+fn manthattan_exp(start: Point, end: Point, rows: &[usize], cols: &[usize], coef: usize) -> usize {
+    let mut dist = start.manhattan(end);
+    for row in rows {
+        if start.y < end.y && row > &start.y && row < &end.y {
+            dist += coef - 1;
+        }
+        if start.y > end.y && row > &end.y && row < &start.y {
+            dist += coef - 1;
+        }
+    }
+    for col in cols {
+        if start.x < end.x && col > &start.x && col < &end.x {
+            dist += coef - 1;
+        }
+        if start.x > end.x && col > &end.x && col < &start.x {
+            dist += coef - 1;
+        }
+    }
+    dist
+}
+
+/// Sum of the distances between galaxies once every empty row and column is `coef` times larger.
+pub fn solve(galaxies: &[Point], rows: &[usize], cols: &[usize], coef: usize) -> Result<usize> {
+    let mut dmap: Distances = HashMap::new();
+    galaxies.iter().for_each(|g1| {
+        galaxies.iter().for_each(|g2| {
+            if g1 != g2 && !dmap.contains_key(&(*g2, *g1)) {
+                dmap.insert((*g1, *g2), manthattan_exp(*g1, *g2, rows, cols, coef));
+            }
+        })
+    });
+    Ok(dmap.values().sum::<usize>())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    type Input = (Galaxy, Vec<usize>, Vec<usize>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((galaxies, rows, cols): &Self::Input) -> Result<Self::Answer> {
+        solve(galaxies, rows, cols, 2)
+    }
+
+    fn part_two((galaxies, rows, cols): &Self::Input) -> Result<Self::Answer> {
+        solve(galaxies, rows, cols, 1000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::solve;
+    use crate::examples;
+    use crate::point::Point;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day11").unwrap().input;
+        let (galaxies, rows, cols) = parse(input).unwrap();
+        let res = solve(&galaxies, &rows, &cols, 2).unwrap();
+        let exp = 374;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day11").unwrap().input;
+        let (galaxies, rows, cols) = parse(input).unwrap();
+        let res = solve(&galaxies, &rows, &cols, 10).unwrap();
+        let exp = 1030;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = solve(&galaxies, &rows, &cols, 100).unwrap();
+        let exp = 8410;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            image in (1..10usize).prop_flat_map(|w| {
+                prop::collection::vec(prop::collection::vec(any::<bool>(), w), 1..10)
+            }),
+        ) {
+            let input = image
+                .iter()
+                .map(|row| row.iter().map(|g| if *g { '#' } else { '.' }).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = parse(&input).unwrap();
+            let galaxies = image
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| (0..row.len()).filter(|x| row[*x]).map(move |x| Point::new(x, y)))
+                .collect::<Vec<_>>();
+            let rows = (0..image.len())
+                .filter(|y| !image[*y].contains(&true))
+                .collect::<Vec<_>>();
+            let cols = (0..image[0].len())
+                .filter(|x| image.iter().all(|row| !row[*x]))
+                .collect::<Vec<_>>();
+            let exp = (galaxies, rows, cols);
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
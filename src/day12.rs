@@ -0,0 +1,272 @@
+use crate::parse::{Locator, ParseError};
+use crate::Solution;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use tracing::trace;
+
+/// Condition of a row of springs, `.` operational, `#` damaged or `?` unknown, with the sizes
+/// of its groups of damaged springs.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Record {
+    condition: Vec<char>,
+    groups: Vec<usize>,
+}
+
+type Cache = HashMap<Record, usize>;
+
+impl Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let groups = self
+            .groups
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "{} {}",
+            self.condition.iter().collect::<String>(),
+            groups.join(",")
+        )
+    }
+}
+
+impl Record {
+    /// Record of `condition`, which may only have the characters `.#?`.
+    pub fn new(condition: &str, groups: Vec<usize>) -> Result<Self, ParseError> {
+        Locator::new(Day12::DAY, condition).expect_chars(condition, ".#?")?;
+        Ok(Self {
+            condition: condition.chars().collect(),
+            groups,
+        })
+    }
+
+    pub fn condition(&self) -> &[char] {
+        &self.condition
+    }
+
+    pub fn groups(&self) -> &[usize] {
+        &self.groups
+    }
+
+    /// Number of ways the unknown springs can be operational or damaged to match the groups.
+    pub fn arrangements(&self) -> usize {
+        self.valid_arrangements(&mut HashMap::new())
+    }
+
+    /// Record five times as long, copies of the condition being separated by unknown springs.
+    pub fn unfold(&self) -> Record {
+        let mut condition: Vec<char> = vec![];
+        let mut groups: Vec<usize> = vec![];
+        (0..5).for_each(|i| {
+            self.condition.iter().for_each(|c| condition.push(*c));
+            if i != 4 {
+                condition.push('?');
+            }
+            self.groups.iter().for_each(|g| groups.push(*g));
+        });
+        Record { condition, groups }
+    }
+
+    fn valid_arrangements(&self, cache: &mut Cache) -> usize {
+        if let Some(count) = cache.get(self) {
+            trace!(record = %self, count, "memo hit");
+            return *count;
+        }
+
+        if self.groups.is_empty() {
+            if !self.condition.contains(&'#') {
+                return 1;
+            } else {
+                return 0;
+            }
+        }
+
+        if self.condition.is_empty() {
+            return 0;
+        }
+
+        let next_char = self.condition[0];
+
+        let retval = match next_char {
+            '#' => self.pound(cache),
+            '.' => self.dot(cache),
+            '?' => self.pound(cache) + self.dot(cache),
+            _ => unreachable!(),
+        };
+
+        trace!(record = %self, count = retval, "memo miss");
+        cache.insert(self.clone(), retval);
+        retval
+    }
+
+    fn pound(&self, cache: &mut Cache) -> usize {
+        let next_group = self.groups[0];
+        let this_group: Vec<char> =
+            self.condition[..usize::min(next_group, self.condition.len())].to_vec();
+
+        if this_group.len() != next_group || !this_group.iter().all(|&c| c != '.') {
+            return 0;
+        }
+
+        if self.condition.len() == next_group {
+            if self.groups.len() == 1 {
+                return 1;
+            } else {
+                return 0;
+            }
+        }
+
+        if self.condition[next_group] != '#' {
+            let rest = Record {
+                condition: self.condition[next_group + 1..].to_vec(),
+                groups: self.groups[1..].to_vec(),
+            };
+            return rest.valid_arrangements(cache);
+        }
+
+        0
+    }
+
+    fn dot(&self, cache: &mut Cache) -> usize {
+        let rest = Record {
+            condition: self.condition[1..].to_vec(),
+            groups: self.groups.clone(),
+        };
+        rest.valid_arrangements(cache)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    let loc = Locator::new(Day12::DAY, input);
+    let mut result = vec![];
+    for line in input.lines() {
+        let (condition, groups) = loc.split_once(line, " ")?;
+        loc.expect_chars(condition, ".#?")?;
+        let condition = condition.chars().collect::<Vec<char>>();
+        let groups = groups
+            .split(',')
+            .map(|c| loc.number::<usize>(c))
+            .collect::<Result<Vec<usize>, _>>()?;
+        result.push(Record { condition, groups });
+    }
+    Ok(result)
+}
+
+/// Sum of the arrangements of every record, sharing the memo between records.
+pub fn part_one(records: &[Record]) -> Result<usize> {
+    let mut cache: Cache = HashMap::new();
+    Ok(records
+        .iter()
+        .map(|r| r.valid_arrangements(&mut cache))
+        .sum())
+}
+
+/// Sum of the arrangements of every unfolded record.
+pub fn part_two(records: &[Record]) -> Result<usize> {
+    let records = records.iter().map(Record::unfold).collect::<Vec<Record>>();
+    let mut cache: Cache = HashMap::new();
+    Ok(records
+        .iter()
+        .map(|r| r.valid_arrangements(&mut cache))
+        .sum())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    type Input = Vec<Record>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(records: &Self::Input) -> Result<Self::Answer> {
+        part_one(records)
+    }
+
+    fn part_two(records: &Self::Input) -> Result<Self::Answer> {
+        part_two(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::part_one;
+    use super::part_two;
+    use super::Record;
+    use crate::examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = examples::get("day12").unwrap().input;
+        let records = parse(input).unwrap();
+        let res = part_one(&records).unwrap();
+        let exp = 21;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let record = Record::new("?###????????", vec![3, 2, 1]).unwrap();
+        let res = (record.arrangements(), record.unfold().arrangements());
+        let exp = (10, 506250);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_record_error() {
+        let res = Record::new("x?", vec![1]).unwrap_err().to_string();
+        let exp = "day 12, line 1, column 1: unexpected character `x`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = examples::get("day12").unwrap().input;
+        let records = parse(input).unwrap();
+        let res = part_two(&records).unwrap();
+        let exp = 525152;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "???.### 1,1,3\n\
+                     .??..??...?##. 1,1;3";
+        let res = parse(input).unwrap_err().to_string();
+        let exp = "day 12, line 2, column 18: invalid number `1;3`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(
+            records in prop::collection::vec(
+                ("[.#?]{1,20}", prop::collection::vec(1..10usize, 1..6)),
+                1..10,
+            ),
+        ) {
+            let input = records
+                .iter()
+                .map(|(condition, groups)| {
+                    let groups = groups.iter().map(|g| g.to_string()).collect::<Vec<_>>();
+                    format!("{} {}", condition, groups.join(","))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = parse(&input).unwrap();
+            let exp = records
+                .into_iter()
+                .map(|(condition, groups)| Record::new(&condition, groups).unwrap())
+                .collect::<Vec<_>>();
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::solution::Puzzle;
+
+/// Every solved day built with its feature, in calendar order.
+pub const DAYS: &[Puzzle] = &[
+    #[cfg(feature = "day01")]
+    Puzzle::of::<crate::day01::Day01>(),
+    #[cfg(feature = "day02")]
+    Puzzle::of::<crate::day02::Day02>(),
+    #[cfg(feature = "day03")]
+    Puzzle::of::<crate::day03::Day03>(),
+    #[cfg(feature = "day04")]
+    Puzzle::of::<crate::day04::Day04>(),
+    #[cfg(feature = "day05")]
+    Puzzle::of::<crate::day05::Day05>(),
+    #[cfg(feature = "day06")]
+    Puzzle::of::<crate::day06::Day06>(),
+    #[cfg(feature = "day07")]
+    Puzzle::of::<crate::day07::Day07>(),
+    #[cfg(feature = "day08")]
+    Puzzle::of::<crate::day08::Day08>(),
+    #[cfg(feature = "day09")]
+    Puzzle::of::<crate::day09::Day09>(),
+    #[cfg(feature = "day10")]
+    Puzzle::of::<crate::day10::Day10>(),
+    #[cfg(feature = "day11")]
+    Puzzle::of::<crate::day11::Day11>(),
+    #[cfg(feature = "day12")]
+    Puzzle::of::<crate::day12::Day12>(),
+];
+
+pub fn get(day: u8) -> Option<&'static Puzzle> {
+    DAYS.iter().find(|p| p.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DAYS;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(input in "\\PC*") {
+            for puzzle in DAYS {
+                let _ = puzzle.parse(&input);
+            }
+        }
+
+        // closer to the puzzle inputs, to get past the first checks of every parser
+        #[test]
+        fn test_parse_never_panics_on_puzzle_like_input(
+            input in "([0-9A-Za-z:;,|=()#.?* -]{0,20}\n){0,8}",
+        ) {
+            for puzzle in DAYS {
+                let _ = puzzle.parse(&input);
+            }
+        }
+    }
+}
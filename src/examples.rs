@@ -0,0 +1,107 @@
+use crate::solution::Part;
+
+/// Example input from a puzzle description, with the answers the description gives for it.
+#[derive(Debug)]
+pub struct Example {
+    pub name: &'static str,
+    pub day: u8,
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+// Embeds `examples/<name>.txt`
+macro_rules! example {
+    ($name:literal, $day:literal, $part_one:expr, $part_two:expr) => {
+        Example {
+            name: $name,
+            day: $day,
+            input: include_str!(concat!("../examples/", $name, ".txt")),
+            part_one: $part_one,
+            part_two: $part_two,
+        }
+    };
+}
+
+/// Every example, in calendar order.
+pub const EXAMPLES: &[Example] = &[
+    example!("day01_a", 1, Some("142"), None),
+    example!("day01_b", 1, None, Some("281")),
+    example!("day02", 2, Some("8"), Some("2286")),
+    example!("day03", 3, Some("4361"), Some("467835")),
+    example!("day04", 4, Some("13"), Some("30")),
+    example!("day05", 5, Some("35"), Some("46")),
+    example!("day06", 6, Some("288"), Some("71503")),
+    example!("day07", 7, Some("6440"), Some("5905")),
+    example!("day08_a", 8, Some("2"), None),
+    example!("day08_b", 8, Some("6"), None),
+    example!("day08_c", 8, None, Some("6")),
+    example!("day09", 9, Some("114"), Some("2")),
+    example!("day10_a", 10, Some("4"), None),
+    example!("day10_b", 10, Some("8"), None),
+    example!("day10_c", 10, None, Some("4")),
+    example!("day11", 11, Some("374"), None),
+    example!("day12", 12, Some("21"), Some("525152")),
+];
+
+/// Example named after its file in `examples/`, e.g. `day08_a`.
+pub fn get(name: &str) -> Option<&'static Example> {
+    EXAMPLES.iter().find(|e| e.name == name)
+}
+
+pub fn for_day(day: u8) -> impl Iterator<Item = &'static Example> {
+    EXAMPLES.iter().filter(move |e| e.day == day)
+}
+
+/// First example of `day` with a known answer for `part`.
+pub fn for_part(day: u8, part: Part) -> Option<&'static Example> {
+    for_day(day).find(|e| e.answer(part).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::for_part;
+    use super::EXAMPLES;
+    use crate::days;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_examples() {
+        for example in EXAMPLES {
+            // days left out of the build have no puzzle to check their examples against
+            let Some(puzzle) = days::get(example.day) else {
+                continue;
+            };
+            let parsed = puzzle.parse(example.input).unwrap();
+            for part in Part::ALL {
+                if let Some(exp) = example.answer(part) {
+                    let res = puzzle.solve(&parsed, part).unwrap();
+                    assert!(
+                        res == exp,
+                        "{} part {}: result = {}, expected = {}",
+                        example.name,
+                        part,
+                        res,
+                        exp
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_for_part() {
+        let res = for_part(8, Part::Two).map(|e| e.name);
+        let exp = Some("day08_c");
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+}
@@ -0,0 +1,514 @@
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use rand::seq::{index, IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+/// Size knobs of a generated input; each day only uses the ones that make sense for it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Size {
+    /// Lines of the input: games, cards, hands, histories, records, nodes, ranges per map...
+    pub lines: usize,
+    /// Width of the grid, or length of each line
+    pub width: usize,
+    /// Height of the grid
+    pub height: usize,
+    /// Largest number in the input
+    pub max: u64,
+}
+
+impl Size {
+    /// Size of the actual puzzle input of `day`.
+    pub fn default_for(day: u8) -> Size {
+        let (lines, width, height, max) = match day {
+            1 => (1000, 30, 0, 0),
+            2 => (100, 0, 0, 20),
+            3 => (0, 140, 140, 999),
+            4 => (200, 0, 0, 99),
+            5 => (30, 0, 0, 1 << 32),
+            6 => (3, 0, 0, 99),
+            7 => (1000, 0, 0, 1000),
+            8 => (700, 270, 0, 0),
+            9 => (200, 21, 0, 10),
+            10 | 11 => (0, 140, 140, 0),
+            12 => (1000, 20, 0, 0),
+            _ => (0, 0, 0, 0),
+        };
+        Size {
+            lines,
+            width,
+            height,
+            max,
+        }
+    }
+}
+
+/// Largest number the parser of `day` accepts, to which `max` is clamped.
+fn limit(day: u8) -> u64 {
+    match day {
+        // cube counts and card numbers
+        2 | 4 => u32::MAX as u64,
+        // map ranges are offset by an `i64`
+        5 => i64::MAX as u64,
+        6 | 7 => usize::MAX as u64,
+        9 => isize::MAX as u64,
+        _ => u64::MAX,
+    }
+}
+
+/// Random valid input for `day`, always the same for a given size and seed.
+///
+/// `size.max` is clamped to the largest number the day can parse, and `size.lines` to at
+/// least one line, since no day parses an empty input.
+pub fn generate(day: u8, size: &Size, seed: u64) -> Option<String> {
+    let size = &Size {
+        lines: size.lines.max(1),
+        max: size.max.min(limit(day)),
+        ..*size
+    };
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    let input = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+type Random = ChaCha8Rng;
+
+fn join(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|l| l + "\n").collect()
+}
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn day01(rng: &mut Random, size: &Size) -> String {
+    join((0..size.lines).map(|_| {
+        let mut line = String::new();
+        while line.len() < size.width {
+            match rng.random_range(0..8) {
+                0 => line.push(rng.random_range('1'..='9')),
+                1 => line.push_str(SPELLED.choose(rng).unwrap()),
+                _ => line.push(rng.random_range('a'..='z')),
+            }
+        }
+        // part one needs an actual digit on every line
+        if !line.contains(|c: char| c.is_ascii_digit()) {
+            line.insert(
+                rng.random_range(0..=line.len()),
+                rng.random_range('1'..='9'),
+            );
+        }
+        line
+    }))
+}
+
+fn day02(rng: &mut Random, size: &Size) -> String {
+    let mut colors = ["red", "green", "blue"];
+    // game ids are bytes
+    join((1..=size.lines.min(u8::MAX as usize)).map(|id| {
+        let sets = (0..rng.random_range(1..=6))
+            .map(|_| {
+                colors.shuffle(rng);
+                colors[..rng.random_range(1..=3)]
+                    .iter()
+                    .map(|c| format!("{} {}", rng.random_range(1..=size.max.max(1)), c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        format!("Game {}: {}", id, sets.join("; "))
+    }))
+}
+
+fn day03(rng: &mut Random, size: &Size) -> String {
+    join((0..size.height).map(|_| {
+        let mut row = String::new();
+        while row.len() < size.width {
+            match rng.random_range(0..10) {
+                0 => row.push(*b"*#+$/=%@&-".choose(rng).unwrap() as char),
+                1 | 2 => {
+                    let number = rng.random_range(1..=size.max.max(1)).to_string();
+                    if row.len() + number.len() <= size.width {
+                        row += &number;
+                    }
+                    // keep numbers apart so that they do not merge
+                    if row.len() < size.width {
+                        row.push('.');
+                    }
+                }
+                _ => row.push('.'),
+            }
+        }
+        row
+    }))
+}
+
+fn day04(rng: &mut Random, size: &Size) -> String {
+    let (win, have) = (10, 25);
+    let max = size.max.max(win + have) as usize;
+    let width = max.to_string().len();
+    join((1..=size.lines).map(|id| {
+        let numbers = index::sample(rng, max, (win + have) as usize).into_vec();
+        let (winning, others) = numbers.split_at(win as usize);
+        // few matches, and never past the last card, as in the puzzle input
+        let matches = if rng.random_bool(0.25) {
+            rng.random_range(1..=5).min(size.lines - id)
+        } else {
+            0
+        };
+        let mut mine = [&winning[..matches], &others[..have as usize - matches]].concat();
+        mine.shuffle(rng);
+        let format = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>1$}", n + 1, width))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        format!("Card {:>3}: {} | {}", id, format(winning), format(&mine))
+    }))
+}
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+fn day05(rng: &mut Random, size: &Size) -> String {
+    let lines = size.lines.max(1);
+    let max = size.max.max(lines as u64 + 1);
+    let seeds = (0..10)
+        .map(|_| {
+            let start = rng.random_range(0..max);
+            let len = rng.random_range(1..=(max - start).div_ceil(10));
+            format!("{} {}", start, len)
+        })
+        .collect::<Vec<_>>();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        // disjoint source ranges covering `0..max`
+        let mut cuts = vec![0, max];
+        cuts.extend(
+            index::sample(rng, max as usize - 1, lines - 1)
+                .into_iter()
+                .map(|c| c as u64 + 1),
+        );
+        cuts.sort();
+        let mut ranges = cuts
+            .windows(2)
+            .map(|w| {
+                let (src, len) = (w[0], w[1] - w[0]);
+                format!("{} {} {}", rng.random_range(0..=max - len), src, len)
+            })
+            .collect::<Vec<_>>();
+        ranges.shuffle(rng);
+        input += &format!("\n{} map:\n{}", name, join(ranges));
+    }
+    input
+}
+
+fn day06(rng: &mut Random, size: &Size) -> String {
+    let races = (0..size.lines)
+        .map(|_| {
+            let time = rng.random_range(1..=size.max.max(1));
+            // past `u64::MAX` the best distance does not fit, but any distance that does is beaten
+            let best = (time / 2).saturating_mul(time - time / 2);
+            (time, rng.random_range(0..best.max(1)))
+        })
+        .collect::<Vec<_>>();
+    let times = races.iter().map(|r| format!(" {:>4}", r.0));
+    let distances = races.iter().map(|r| format!(" {:>4}", r.1));
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        times.collect::<String>(),
+        distances.collect::<String>()
+    )
+}
+
+fn day07(rng: &mut Random, size: &Size) -> String {
+    let labels = b"23456789TJQKA";
+    let mut hands = HashSet::new();
+    // hands are all different
+    while hands.len() < size.lines.min(labels.len().pow(5)) {
+        hands.insert(
+            (0..5)
+                .map(|_| *labels.choose(rng).unwrap() as char)
+                .collect::<String>(),
+        );
+    }
+    let mut hands = hands.into_iter().collect::<Vec<_>>();
+    hands.sort();
+    hands.shuffle(rng);
+    join(
+        hands
+            .into_iter()
+            .map(|h| format!("{} {}", h, rng.random_range(1..=size.max.max(1)))),
+    )
+}
+
+fn day08(rng: &mut Random, size: &Size) -> String {
+    let instr = (0..size.width.max(1))
+        .map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+    let letters = || 'B'..='Y';
+    let mut prefixes = letters()
+        .flat_map(|a| letters().map(move |b| format!("{}{}", a, b)))
+        .collect::<Vec<_>>();
+    prefixes.shuffle(rng);
+    let mut names = prefixes
+        .iter()
+        .flat_map(|p| letters().map(move |c| format!("{}{}", p, c)))
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+    // each ghost walks its own cycle, from `..A` to `..Z` and back to the start of the cycle
+    let ghosts = (size.lines / 4).clamp(1, 6);
+    let per_ghost = (size.lines.min(names.len()) / ghosts).max(3) - 2;
+    let mut nodes = Vec::new();
+    for ghost in 0..ghosts {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (
+                format!("{}A", prefixes[2 * ghost]),
+                format!("{}Z", prefixes[2 * ghost + 1]),
+            ),
+        };
+        let len = rng.random_range(per_ghost.div_ceil(2)..=per_ghost);
+        let mut cycle = vec![start];
+        cycle.extend(names.drain(..len));
+        cycle.push(end);
+        for pair in cycle.windows(2) {
+            nodes.push(format!("{0} = ({1}, {1})", pair[0], pair[1]));
+        }
+        nodes.push(format!("{0} = ({1}, {1})", cycle[len + 1], cycle[1]));
+    }
+    nodes.shuffle(rng);
+    format!("{}\n\n{}", instr, join(nodes))
+}
+
+fn day09(rng: &mut Random, size: &Size) -> String {
+    // coefficients small enough for the values up to the next one to fit in an `i64`
+    let reach = (0..6).fold(0u64, |sum, k| {
+        sum.saturating_add((size.width as u64).saturating_pow(k))
+    });
+    let max = size.max.min(i64::MAX as u64 / reach) as i64;
+    join((0..size.lines).map(|_| {
+        let coefs = (0..rng.random_range(1..=6))
+            .map(|_| rng.random_range(-max..=max))
+            .collect::<Vec<_>>();
+        (0..size.width as i64)
+            .map(|n| {
+                let value = coefs.iter().rev().fold(0, |acc, c| acc * n + c);
+                value.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
+
+/// Pipe connecting the tile to its neighbours in directions `a` and `b`.
+fn pipe(a: Direction, b: Direction) -> char {
+    use Direction::*;
+    match (a, b) {
+        (N, S) | (S, N) => '|',
+        (E, W) | (W, E) => '-',
+        (N, E) | (E, N) => 'L',
+        (N, W) | (W, N) => 'J',
+        (S, W) | (W, S) => '7',
+        _ => 'F',
+    }
+}
+
+fn day10(rng: &mut Random, size: &Size) -> String {
+    let (width, height) = (size.width.max(3), size.height.max(3));
+    let mut grid = Grid::filled(width, height, '.');
+    for tile in grid.positions().collect::<Vec<_>>() {
+        grid[tile] = *b"|-LJ7F...".choose(rng).unwrap() as char;
+    }
+    // the loop goes around a histogram of random columns, drawn at twice the scale so that
+    // its sides never touch
+    let (cols, rows) = ((width - 1) / 2, (height - 1) / 2);
+    let first = rng.random_range(0..cols);
+    let last = rng.random_range(first..cols);
+    let mut corners = vec![Point::new(first, rows), Point::new(last + 1, rows)];
+    for col in (first..=last).rev() {
+        let top = rng.random_range(0..rows);
+        corners.push(Point::new(col + 1, top));
+        corners.push(Point::new(col, top));
+    }
+    let mut path = vec![Point::new(2 * first, 2 * rows)];
+    for corner in corners.iter().skip(1).chain(corners.first()) {
+        let target = Point::new(2 * corner.x, 2 * corner.y);
+        while let Some(&tile) = path.last().filter(|&&t| t != target) {
+            let direction = if target.x != tile.x {
+                if target.x > tile.x {
+                    Direction::E
+                } else {
+                    Direction::W
+                }
+            } else if target.y > tile.y {
+                Direction::S
+            } else {
+                Direction::N
+            };
+            path.push(tile.step(direction).unwrap());
+        }
+    }
+    path.pop();
+    let towards = |from: Point, to: Point| {
+        *Direction::CARDINAL
+            .iter()
+            .find(|d| from.step(**d) == Some(to))
+            .unwrap()
+    };
+    for (i, tile) in path.iter().enumerate() {
+        let prev = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        grid[*tile] = pipe(towards(*tile, prev), towards(*tile, next));
+    }
+    let start = *path.choose(rng).unwrap();
+    grid[start] = 'S';
+    // nothing but the loop may connect to the start
+    for tile in grid.neighbours4(start).collect::<Vec<_>>() {
+        if !path.contains(&tile) {
+            grid[tile] = '.';
+        }
+    }
+    grid.to_string() + "\n"
+}
+
+fn day11(rng: &mut Random, size: &Size) -> String {
+    join((0..size.height).map(|_| {
+        (0..size.width)
+            .map(|_| if rng.random_bool(0.025) { '#' } else { '.' })
+            .collect()
+    }))
+}
+
+fn day12(rng: &mut Random, size: &Size) -> String {
+    let width = size.width.max(1);
+    join((0..size.lines).map(|_| {
+        let mut springs = (0..rng.random_range(width.div_ceil(2)..=width))
+            .map(|_| rng.random_bool(0.5))
+            .collect::<Vec<_>>();
+        if !springs.contains(&true) {
+            let i = rng.random_range(0..springs.len());
+            springs[i] = true;
+        }
+        let groups = springs
+            .split(|damaged| !damaged)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect::<Vec<_>>();
+        let condition = springs
+            .iter()
+            .map(|damaged| match (rng.random_bool(0.4), damaged) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect::<String>();
+        format!("{} {}", condition, groups.join(","))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use super::Size;
+    use crate::days::DAYS;
+    use crate::solution::Part;
+    use proptest::prelude::*;
+
+    fn small(day: u8) -> Size {
+        let size = Size::default_for(day);
+        Size {
+            lines: size.lines.min(20),
+            width: size.width.min(20),
+            height: size.height.min(20),
+            ..size
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_are_valid() {
+        for seed in 0..10 {
+            for puzzle in DAYS {
+                // new days have no generator yet
+                let Some(input) = generate(puzzle.day, &small(puzzle.day), seed) else {
+                    continue;
+                };
+                let parsed = puzzle.parse(&input).unwrap();
+                for part in Part::ALL {
+                    let res = puzzle.solve(&parsed, part);
+                    assert!(
+                        res.is_ok(),
+                        "day {} part {} seed {}: {:?}",
+                        puzzle.day,
+                        part,
+                        seed,
+                        res
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let size = small(12);
+        let res = generate(12, &size, 7);
+        let exp = generate(12, &size, 7);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        assert!(generate(12, &size, 8) != exp);
+    }
+
+    proptest! {
+        #[test]
+        fn test_extreme_sizes_parse(seed in any::<u64>()) {
+            for puzzle in DAYS {
+                let largest = Size {
+                    max: u64::MAX,
+                    ..small(puzzle.day)
+                };
+                let empty = Size {
+                    lines: 0,
+                    ..small(puzzle.day)
+                };
+                for size in [largest, empty] {
+                    let Some(input) = generate(puzzle.day, &size, seed) else {
+                        continue;
+                    };
+                    let res = puzzle.parse(&input);
+                    prop_assert!(
+                        res.is_ok(),
+                        "day {} size {:?} seed {}: {:?}",
+                        puzzle.day,
+                        size,
+                        seed,
+                        res.err()
+                    );
+                }
+            }
+        }
+    }
+}
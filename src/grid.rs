@@ -0,0 +1,282 @@
+use crate::parse::{Locator, ParseError};
+use crate::point::{Direction, Point};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// Rectangular grid of cells, addressed by `(x, y)` with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells, in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert!(cells.len() == width * height);
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line of `input`, converting every character with `cell`.
+    ///
+    /// Characters for which `cell` returns `None` and rows of different lengths are
+    /// reported as errors.
+    pub fn parse(
+        loc: &Locator,
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.trim_end().lines() {
+            let mut len = 0;
+            for (i, chr) in line.char_indices() {
+                let value = cell(chr).ok_or_else(|| {
+                    loc.error(&line[i..i + chr.len_utf8()], "unexpected character")
+                })?;
+                cells.push(value);
+                len += 1;
+            }
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(loc.error(line, format!("expected {} cells in row", width)))
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if p.x < self.width && p.y < self.height {
+            Some(&self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if p.x < self.width && p.y < self.height {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell of the grid with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// In-bounds positions north, east, south and west of `p`.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Direction::CARDINAL)
+    }
+
+    /// In-bounds positions around `p`, diagonals included.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Direction::ALL)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        p: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .filter_map(move |d| p.step(*d).filter(|n| self.get(*n).is_some()))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Grid mirrored along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flat_map(|c| c.cloned()).collect();
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(Point::new(x, y))
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(Point::new(x, y))
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        &self[(p.x, p.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        &mut self[(p.x, p.y)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::parse::Locator;
+    use crate::point::Point;
+    use proptest::prelude::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(&Locator::new(0, input), input, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let res = grid("ab\ncd\nef\n");
+        let exp = Grid::new(2, 3, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "ab\ncd\ne";
+        let res = Grid::parse(&Locator::new(0, input), input, Some)
+            .unwrap_err()
+            .to_string();
+        let exp = "day 0, line 3, column 1: expected 2 cells in row `e`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let input = "ab\nc#";
+        let res = Grid::parse(&Locator::new(0, input), input, |c| {
+            c.is_alphabetic().then_some(c)
+        })
+        .unwrap_err()
+        .to_string();
+        let exp = "day 0, line 2, column 2: unexpected character `#`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid("abc\ndef\nghi");
+        let res = grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>();
+        let exp = vec![Point::new(1, 0), Point::new(0, 1)];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        let res = grid.neighbours8(Point::new(1, 1)).count();
+        let exp = 8;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = grid.neighbours8(Point::new(2, 2)).collect::<Vec<_>>();
+        let exp = vec![Point::new(2, 1), Point::new(1, 2), Point::new(1, 1)];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid("abc\ndef");
+        let res = grid
+            .rows()
+            .map(|r| r.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        let exp = vec!["abc", "def"];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        let res = grid
+            .columns()
+            .map(|c| c.collect::<String>())
+            .collect::<Vec<_>>();
+        let exp = vec!["ad", "be", "cf"];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_transpose_and_display() {
+        let grid = grid("abc\ndef");
+        let res = grid.transpose().to_string();
+        let exp = "ad\nbe\ncf";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+        let res = grid.transpose().transpose();
+        assert!(res == grid, "result = {:?}, expected = {:?}", res, grid);
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(rows in (1..20usize).prop_flat_map(|w| {
+            let cell = prop::char::any().prop_filter("whitespace", |c| !c.is_whitespace());
+            prop::collection::vec(prop::collection::vec(cell, w), 1..20)
+        })) {
+            let input = rows
+                .iter()
+                .map(|r| r.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let res = Grid::parse(&Locator::new(0, &input), &input, Some).unwrap();
+            let exp = Grid::new(rows[0].len(), rows.len(), rows.concat());
+            prop_assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+            let res = res.to_string();
+            prop_assert!(res == input, "result = {}, expected = {}", res, input);
+        }
+    }
+}
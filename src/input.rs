@@ -0,0 +1,142 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fmt::{self, Display};
+use std::fs::read_to_string;
+use std::io::{stdin, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Configuration file looked up in the current directory.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+const DEFAULT_INPUT_DIR: &str = "./input";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Loads `aoc.toml` from the current directory, if there is one.
+    pub fn load() -> Result<Self> {
+        let path = Path::new(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let text = read_to_string(path)
+            .with_context(|| format!("could not read file `{}`", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("invalid config file `{}`", path.display()))
+    }
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Interprets a command line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Default input of `day`, `dayNN.txt` in the configured input directory.
+    pub fn default_for(day: u8) -> Result<Self> {
+        Ok(Source::File(
+            input_dir()?.join(format!("day{:02}.txt", day)),
+        ))
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        let res = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Source::File(path) => read_to_string(path),
+        };
+        res.with_context(|| format!("could not read input of day {} from {}", day, self))
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "`{}`", path.display()),
+        }
+    }
+}
+
+/// Directory puzzle inputs are read from: `$AOC_INPUT_DIR`, then `input_dir` in `aoc.toml`,
+/// then `./input`.
+pub fn input_dir() -> Result<PathBuf> {
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        return Ok(PathBuf::from(dir));
+    }
+    Ok(Config::load()?
+        .input_dir
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR)))
+}
+
+/// Reads the input of `day` from `arg` if given, from its default location otherwise.
+pub fn read(day: u8, arg: Option<&str>) -> Result<String> {
+    let source = match arg {
+        Some(arg) => Source::from_arg(arg),
+        None => Source::default_for(day)?,
+    };
+    source.read(day)
+}
+
+/// Hex-encoded SHA-256 of a puzzle input, to tell apart results computed on different inputs.
+pub fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::checksum;
+    use super::Config;
+    use super::Source;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_source_from_arg() {
+        let res = Source::from_arg("-");
+        let exp = Source::Stdin;
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        let res = Source::from_arg("input/day01.txt");
+        let exp = Source::File(PathBuf::from("input/day01.txt"));
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_config() {
+        let config: Config = toml::from_str("input_dir = \"/tmp/aoc\"").unwrap();
+        let res = config.input_dir;
+        let exp = Some(PathBuf::from("/tmp/aoc"));
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        assert!(toml::from_str::<Config>("inputdir = \"/tmp/aoc\"").is_err());
+    }
+
+    #[test]
+    fn test_checksum() {
+        let res = checksum("abc");
+        let exp = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+}
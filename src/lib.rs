@@ -0,0 +1,61 @@
+//! Solutions to the puzzles of [Advent of Code 2023](https://adventofcode.com/2023).
+//!
+//! Every day is a module, `day01` to `day12`, with the types its input is parsed into, a
+//! `parse` function reporting malformed input as a [`ParseError`] and the functions solving
+//! each part, so that they can be used on their own:
+//!
+//! ```
+//! # #[cfg(feature = "day12")]
+//! # {
+//! use aoc2023::day12::Record;
+//!
+//! let record = Record::new("???.###", vec![1, 1, 3])?;
+//! assert_eq!(record.arrangements(), 1);
+//! assert_eq!(record.unfold().arrangements(), 1);
+//! # }
+//! # Ok::<(), aoc2023::ParseError>(())
+//! ```
+//!
+//! Each day also implements [`Solution`], through which the `aoc` binary runs them all. Days
+//! are Cargo features, `day01` to `day12`, all enabled by default; a day left out is not built,
+//! nor are the dependencies only it needs.
+
+#[cfg(feature = "tools")]
+pub mod answers;
+#[cfg(feature = "day01")]
+pub mod day01;
+#[cfg(feature = "day02")]
+pub mod day02;
+#[cfg(feature = "day03")]
+pub mod day03;
+#[cfg(feature = "day04")]
+pub mod day04;
+#[cfg(feature = "day05")]
+pub mod day05;
+#[cfg(feature = "day06")]
+pub mod day06;
+#[cfg(feature = "day07")]
+pub mod day07;
+#[cfg(feature = "day08")]
+pub mod day08;
+#[cfg(feature = "day09")]
+pub mod day09;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+pub mod days;
+pub mod examples;
+#[cfg(feature = "tools")]
+pub mod generate;
+pub mod grid;
+#[cfg(feature = "tools")]
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod solution;
+
+pub use parse::ParseError;
+pub use solution::Solution;
@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending text.
+///
+/// Lines and columns are 1-based, columns are counted in characters.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Puzzle input of a day, able to build a `ParseError` for any slice borrowed from it.
+#[derive(Debug, Copy, Clone)]
+pub struct Locator<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Locator<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input }
+    }
+
+    /// Builds an error pointing at `at`, which should be a slice of the input; other strings
+    /// are reported at the start of the input.
+    pub fn error(&self, at: &str, reason: impl Into<String>) -> ParseError {
+        let start = self.input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + at.len() <= self.input.len())
+            .unwrap_or(0);
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: at.lines().next().unwrap_or("").to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Parses `at` as a number.
+    pub fn number<T: FromStr>(&self, at: &str) -> Result<T, ParseError> {
+        at.parse::<T>()
+            .map_err(|_| self.error(at, "invalid number"))
+    }
+
+    /// Checks that `text` is only made of characters in `allowed`.
+    pub fn expect_chars(&self, text: &str, allowed: &str) -> Result<(), ParseError> {
+        match text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            Some((i, c)) => Err(self.error(&text[i..i + c.len_utf8()], "unexpected character")),
+            None => Ok(()),
+        }
+    }
+
+    /// Splits `text` around the first occurrence of `delimiter`.
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("expected `{}` in", delimiter)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locator;
+
+    #[test]
+    fn test_error_position() {
+        let input = "Card 1: 41 48\nCard 2: 13 x2";
+        let loc = Locator::new(4, input);
+        let at = input.split_whitespace().last().unwrap();
+        let res = loc.error(at, "invalid number");
+        let pos = (res.line, res.column);
+        assert!(
+            pos == (2, 12),
+            "result = {:?}, expected = {:?}",
+            pos,
+            (2, 12)
+        );
+        let res = res.to_string();
+        let exp = "day 4, line 2, column 12: invalid number `x2`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+
+    #[test]
+    fn test_error_foreign_text() {
+        let input = "Card 1: 41 48";
+        let loc = Locator::new(4, input);
+        let res = loc.number::<u32>("x").unwrap_err().to_string();
+        let exp = "day 4, line 1, column 1: invalid number `x`";
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+}
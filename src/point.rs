@@ -0,0 +1,137 @@
+use std::fmt::{self, Display};
+
+/// Compass direction on a grid whose `y` axis points south.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// North, east, south and west.
+    pub const CARDINAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// Offset of a single step in this direction, as `(dx, dy)`.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::N => (0, -1),
+            Direction::NE => (1, -1),
+            Direction::E => (1, 0),
+            Direction::SE => (1, 1),
+            Direction::S => (0, 1),
+            Direction::SW => (-1, 1),
+            Direction::W => (-1, 0),
+            Direction::NW => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+/// Position on a grid, with `(0, 0)` in the top left corner.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    /// Point moved by `(dx, dy)`, or `None` if a coordinate would be negative or overflow.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+
+    /// Neighbouring point in `direction`, or `None` if it would be off the grid.
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.delta();
+        self.offset(dx, dy)
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+    use super::Point;
+
+    #[test]
+    fn test_step() {
+        let res = Point::new(3, 0).step(Direction::SW);
+        let exp = Some(Point::new(2, 1));
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        let res = Point::new(3, 0).step(Direction::NE);
+        let exp = None;
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_turn() {
+        let res = Direction::ALL.map(|d| d.turn_right().turn_left() == d);
+        let exp = [true; 8];
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+        let res = (
+            Direction::N.turn_right(),
+            Direction::NE.turn_left(),
+            Direction::W.opposite(),
+        );
+        let exp = (Direction::E, Direction::NW, Direction::E);
+        assert!(res == exp, "result = {:?}, expected = {:?}", res, exp);
+    }
+
+    #[test]
+    fn test_manhattan() {
+        let res = Point::new(1, 6).manhattan(Point::new(5, 11));
+        let exp = 9;
+        assert!(res == exp, "result = {}, expected = {}", res, exp);
+    }
+}
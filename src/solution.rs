@@ -0,0 +1,97 @@
+use crate::parse::ParseError;
+use anyhow::{anyhow, Result};
+use std::any::Any;
+use std::fmt::{self, Display};
+use tracing::debug_span;
+
+/// A solver for a single day of the calendar.
+///
+/// The puzzle text is parsed once into `Input`, which is then shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    /// Title of the puzzle, as on adventofcode.com
+    const TITLE: &'static str;
+
+    type Input: 'static;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer>;
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Parsed input of a `Puzzle`, opaque to the caller.
+pub type Parsed = Box<dyn Any>;
+
+/// Type-erased `Solution`, so that every day can be driven through the same value.
+pub struct Puzzle {
+    pub day: u8,
+    pub title: &'static str,
+    parse: fn(&str) -> Result<Parsed>,
+    part_one: fn(&dyn Any) -> Result<String>,
+    part_two: fn(&dyn Any) -> Result<String>,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Self {
+            day: S::DAY,
+            title: S::TITLE,
+            parse: parse_boxed::<S>,
+            part_one: part_one_erased::<S>,
+            part_two: part_two_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        let _span = debug_span!("parse", day = self.day).entered();
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, input: &Parsed, part: Part) -> Result<String> {
+        let _span = debug_span!("solve", day = self.day, part = %part).entered();
+        match part {
+            Part::One => (self.part_one)(input.as_ref()),
+            Part::Two => (self.part_two)(input.as_ref()),
+        }
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Parsed> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S: Solution + 'static>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or(anyhow!("input was not parsed by day {}", S::DAY))
+}
+
+fn part_one_erased<S: Solution + 'static>(input: &dyn Any) -> Result<String> {
+    Ok(S::part_one(downcast::<S>(input)?)?.to_string())
+}
+
+fn part_two_erased<S: Solution + 'static>(input: &dyn Any) -> Result<String> {
+    Ok(S::part_two(downcast::<S>(input)?)?.to_string())
+}